@@ -0,0 +1,52 @@
+use std::thread;
+use std::time::Instant;
+
+use crate::Scout;
+
+// A square map with about `asteroids` asteroids, one cell in three, scattered
+// by a fixed xorshift sequence so every run times the same map.
+pub fn generate(asteroids: usize, seed: u64) -> String {
+    generate_sparse(asteroids, 3, seed)
+}
+
+// the same with one cell in `sparsity` holding an asteroid
+pub fn generate_sparse(asteroids: usize, sparsity: u64, seed: u64) -> String {
+    let side = ((asteroids as f64) * sparsity as f64).sqrt().ceil() as usize;
+    let mut state = seed.max(1);
+    let mut map = String::with_capacity(side * (side + 1));
+    for _ in 0..side {
+        for _ in 0..side {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            map.push(if state.is_multiple_of(sparsity) { '#' } else { '.' });
+        }
+        map.push('\n');
+    }
+    map
+}
+
+pub fn run(asteroids: usize, sparsity: u64) {
+    let map = generate_sparse(asteroids, sparsity, 2019);
+    let scout = Scout::parse(&map).unwrap();
+    let side = map.lines().count();
+    println!("{} asteroids on a {}x{} map", scout.asteroids.len(), side, side);
+
+    let cores = thread::available_parallelism().map_or(1, |n| n.get());
+    let mut threads = vec![1];
+    if cores > 1 {
+        threads.push(cores);
+    }
+    for threads in threads {
+        let start = Instant::now();
+        let station = scout.find_station_with(threads);
+        println!(
+            "{:>3} thread(s): {:>8.3}s, best is ({}, {}) seeing {}",
+            threads,
+            start.elapsed().as_secs_f64(),
+            station.x,
+            station.y,
+            station.can_see
+        );
+    }
+}
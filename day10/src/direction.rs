@@ -0,0 +1,90 @@
+use std::cmp::Ordering;
+
+// The direction from one asteroid to another as the smallest integer step
+// along the line, so two asteroids are in line exactly when their directions
+// are equal. y grows downwards, like the map.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Direction {
+    pub dx: i32,
+    pub dy: i32,
+}
+
+fn gcd(mut a: i32, mut b: i32) -> i32 {
+    while b != 0 {
+        let r = a % b;
+        a = b;
+        b = r;
+    }
+    a.abs()
+}
+
+impl Direction {
+    pub const UP: Direction = Direction { dx: 0, dy: -1 };
+
+    // None for no offset at all, which has no direction
+    pub fn new(dx: i32, dy: i32) -> Option<Direction> {
+        let divisor = gcd(dx, dy);
+        if divisor == 0 {
+            return None;
+        }
+        Some(Direction {
+            dx: dx / divisor,
+            dy: dy / divisor,
+        })
+    }
+
+    // 0 from straight up round to just before straight down, 1 for the rest
+    fn half(&self) -> u8 {
+        if self.dx > 0 || (self.dx == 0 && self.dy < 0) {
+            0
+        } else {
+            1
+        }
+    }
+}
+
+// Clockwise from straight up, as the laser turns. Within a half turn one
+// direction comes before another when the cross product says the second is
+// clockwise of it, so no angles or rounding are involved.
+impl Ord for Direction {
+    fn cmp(&self, other: &Direction) -> Ordering {
+        self.half().cmp(&other.half()).then_with(|| {
+            let cross = self.dx as i64 * other.dy as i64 - self.dy as i64 * other.dx as i64;
+            0.cmp(&cross)
+        })
+    }
+}
+
+impl PartialOrd for Direction {
+    fn partial_cmp(&self, other: &Direction) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reduced() {
+        assert_eq!(Direction::new(4, -6), Some(Direction { dx: 2, dy: -3 }));
+        assert_eq!(Direction::new(0, 5), Some(Direction { dx: 0, dy: 1 }));
+        assert_eq!(Direction::new(0, 0), None);
+        assert_ne!(Direction::new(1_000_000, 999_999), Direction::new(999_999, 999_998));
+    }
+
+    #[test]
+    fn test_clockwise() {
+        let clockwise: Vec<Direction> = [
+            (0, -1), (1, -3), (1, -1), (3, -1), (1, 0), (3, 1), (1, 1),
+            (0, 1), (-1, 1), (-1, 0), (-1, -1), (-1, -1000),
+        ]
+        .iter()
+        .map(|&(dx, dy)| Direction::new(dx, dy).unwrap())
+        .collect();
+        let mut sorted = clockwise.clone();
+        sorted.reverse();
+        sorted.sort();
+        assert_eq!(sorted, clockwise);
+    }
+}
@@ -0,0 +1,142 @@
+use std::io::{self, Write};
+
+use crate::Loc;
+
+// How many other asteroids each asteroid can see, laid out like the map,
+// with the best station picked out.
+pub struct Heatmap {
+    width: usize,
+    height: usize,
+    // one cell per map position, None where there's no asteroid
+    cells: Vec<Option<usize>>,
+    least: usize,
+    most: usize,
+    best: Option<(i32, i32)>,
+}
+
+const EMPTY: [u8; 3] = [20, 20, 30];
+const BEST: [u8; 3] = [255, 255, 255];
+
+// dark blue for the asteroids that see least, through purple to bright red
+// for the ones that see most
+fn heat(fraction: f64) -> [u8; 3] {
+    let fraction = fraction.clamp(0.0, 1.0);
+    [
+        (60.0 + 195.0 * fraction) as u8,
+        (40.0 + 40.0 * fraction) as u8,
+        (200.0 - 170.0 * fraction) as u8,
+    ]
+}
+
+impl Heatmap {
+    // `counts` goes with `asteroids`, one for one
+    pub fn new(width: usize, height: usize, asteroids: &[Loc], counts: &[usize], best: Option<&Loc>) -> Heatmap {
+        let mut cells = vec![None; width * height];
+        for (asteroid, &count) in asteroids.iter().zip(counts) {
+            cells[asteroid.y as usize * width + asteroid.x as usize] = Some(count);
+        }
+        Heatmap {
+            width,
+            height,
+            cells,
+            least: counts.iter().cloned().min().unwrap_or(0),
+            most: counts.iter().cloned().max().unwrap_or(0),
+            best: best.map(|loc| (loc.x, loc.y)),
+        }
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<usize> {
+        self.cells[y * self.width + x]
+    }
+
+    fn color(&self, x: usize, y: usize) -> [u8; 3] {
+        if self.best == Some((x as i32, y as i32)) {
+            return BEST;
+        }
+        match self.get(x, y) {
+            // spread over the range there is, for the most contrast
+            Some(count) if self.most > self.least => {
+                heat((count - self.least) as f64 / (self.most - self.least) as f64)
+            }
+            Some(_) => heat(0.0),
+            None => EMPTY,
+        }
+    }
+
+    // Each asteroid as a `#` on its heat color and the best station as a bold
+    // `X`, in 24-bit terminal colors.
+    pub fn ansi(&self) -> String {
+        let mut text = String::new();
+        for y in 0..self.height {
+            for x in 0..self.width {
+                let [r, g, b] = self.color(x, y);
+                let symbol = if self.best == Some((x as i32, y as i32)) {
+                    "\x1b[1;30mX"
+                } else if self.get(x, y).is_some() {
+                    "#"
+                } else {
+                    " "
+                };
+                text.push_str(&format!("\x1b[48;2;{};{};{}m{}\x1b[0m", r, g, b, symbol));
+            }
+            text.push('\n');
+        }
+        text
+    }
+
+    // each map position as a `scale` by `scale` block; a scale of 0 would
+    // make an image with no pixels at all
+    pub fn write_ppm<W: Write>(&self, out: &mut W, scale: usize) -> io::Result<()> {
+        if scale == 0 {
+            return Err(io::Error::new(io::ErrorKind::InvalidInput, "scale must be at least 1"));
+        }
+        writeln!(out, "P6\n{} {}\n255", self.width * scale, self.height * scale)?;
+        for y in 0..self.height {
+            let mut line = Vec::with_capacity(self.width * scale * 3);
+            for x in 0..self.width {
+                let color = self.color(x, y);
+                for _ in 0..scale {
+                    line.extend_from_slice(&color);
+                }
+            }
+            for _ in 0..scale {
+                out.write_all(&line)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Scout;
+
+    #[test]
+    fn test_heatmap() {
+        let scout = Scout::parse(".#..#\n.....\n#####\n....#\n...##\n").unwrap();
+        let heatmap = scout.heatmap();
+        assert_eq!(heatmap.get(1, 0), Some(7));
+        assert_eq!(heatmap.get(0, 2), Some(6));
+        assert_eq!(heatmap.get(3, 4), Some(8));
+        assert_eq!(heatmap.get(0, 0), None);
+        assert_eq!(heatmap.color(3, 4), BEST);
+        assert_eq!(heatmap.color(0, 0), EMPTY);
+
+        let ansi = heatmap.ansi();
+        assert_eq!(ansi.lines().count(), 5);
+        assert_eq!(ansi.matches('#').count(), 9);
+        assert_eq!(ansi.matches('X').count(), 1);
+
+        let mut ppm = Vec::new();
+        heatmap.write_ppm(&mut ppm, 2).unwrap();
+        let header = "P6\n10 10\n255\n";
+        assert!(ppm.starts_with(header.as_bytes()));
+        assert_eq!(ppm.len(), header.len() + 10 * 10 * 3);
+
+        let mut empty = Vec::new();
+        let err = heatmap.write_ppm(&mut empty, 0).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+        assert!(empty.is_empty());
+    }
+}
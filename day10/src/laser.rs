@@ -0,0 +1,96 @@
+use std::collections::BTreeMap;
+
+use crate::direction::Direction;
+use crate::Loc;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Sweep {
+    Clockwise,
+    CounterClockwise,
+}
+
+// The asteroids in the order the laser hits them: the nearest one in each
+// direction as it sweeps round, then the next nearest on the following turn,
+// and so on. Nothing is worked out ahead of time beyond sorting the rays.
+pub struct Vaporization<'a> {
+    // asteroids in each direction, nearest first, in the order the laser
+    // passes them starting from where it points first
+    rays: Vec<Vec<&'a Loc>>,
+    round: usize,
+    ray: usize,
+}
+
+impl<'a> Vaporization<'a> {
+    // the laser starts pointing along `start` and hits anything there first
+    pub fn new(rays: BTreeMap<Direction, Vec<&'a Loc>>, start: Direction, sweep: Sweep) -> Vaporization<'a> {
+        let mut rays: Vec<(Direction, Vec<&Loc>)> = rays.into_iter().collect();
+        let first = match sweep {
+            Sweep::Clockwise => rays.iter().position(|(direction, _)| *direction >= start),
+            Sweep::CounterClockwise => {
+                rays.reverse();
+                rays.iter().position(|(direction, _)| *direction <= start)
+            }
+        };
+        rays.rotate_left(first.unwrap_or(0));
+        Vaporization {
+            rays: rays.into_iter().map(|(_, ray)| ray).collect(),
+            round: 0,
+            ray: 0,
+        }
+    }
+
+    // how many asteroids are left to hit in the current turn
+    fn left_this_round(&self) -> usize {
+        self.rays[self.ray..]
+            .iter()
+            .filter(|ray| ray.len() > self.round)
+            .count()
+    }
+
+    // moves on to the next turn, if there's anything left for it
+    fn next_round(&mut self) -> bool {
+        if !self.rays.iter().any(|ray| ray.len() > self.round + 1) {
+            return false;
+        }
+        self.round += 1;
+        self.ray = 0;
+        true
+    }
+}
+
+impl<'a> Iterator for Vaporization<'a> {
+    type Item = &'a Loc;
+
+    fn next(&mut self) -> Option<&'a Loc> {
+        loop {
+            if self.ray == self.rays.len() && !self.next_round() {
+                return None;
+            }
+            let ray = &self.rays[self.ray];
+            self.ray += 1;
+            if let Some(loc) = ray.get(self.round) {
+                return Some(*loc);
+            }
+        }
+    }
+
+    // skips whole turns of the laser at a time, so finding the 200th asteroid
+    // only walks the rays of the turn it's in
+    fn nth(&mut self, mut n: usize) -> Option<&'a Loc> {
+        loop {
+            let left = self.left_this_round();
+            if n < left {
+                break;
+            }
+            n -= left;
+            if !self.next_round() {
+                self.ray = self.rays.len();
+                return None;
+            }
+        }
+        for _ in 0..n {
+            self.next();
+        }
+        self.next()
+    }
+}
@@ -0,0 +1,505 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fs;
+use std::thread;
+
+pub mod bench;
+pub mod direction;
+pub mod heatmap;
+pub mod laser;
+use direction::Direction;
+use heatmap::Heatmap;
+use laser::{Sweep, Vaporization};
+
+// An asteroid, or a spot on the map to look from. Two are equal when they're
+// in the same place, whatever they can see.
+#[derive(Debug, Clone, Copy)]
+pub struct Loc {
+    pub can_see: usize,
+    pub x: i32,
+    pub y: i32,
+}
+
+impl Loc {
+    pub fn new(x: i32, y: i32) -> Loc {
+        Loc { can_see: 0, x, y }
+    }
+
+    fn distance(&self, other: &Loc) -> i32 {
+        (other.x - self.x).abs() + (other.y - self.y).abs()
+    }
+}
+
+impl PartialEq for Loc {
+    fn eq(&self, other: &Loc) -> bool {
+        self.x == other.x && self.y == other.y
+    }
+}
+
+impl Eq for Loc {}
+
+pub struct Scout {
+    asteroids: Vec<Loc>,
+    width: usize,
+    height: usize,
+    // the index into `asteroids` of the asteroid at each occupied position,
+    // so sparse maps don't pay for their empty space
+    grid: HashMap<(i32, i32), usize>,
+}
+
+// Every offset between two asteroids on the map, mapped to the cell of its
+// reduced direction. Working that out once per map leaves the O(n²) part
+// with table lookups instead of a gcd for every pair. The table, and the
+// `seen` array each thread keeps beside it, have a cell for every offset, so
+// they grow with the map's area rather than with the number of asteroids.
+struct Offsets {
+    reduced: Vec<u32>,
+    width: i32,
+    height: i32,
+}
+
+// no direction at all, and nothing seen yet
+const NONE: u32 = u32::MAX;
+
+// A table bigger than this many cells per asteroid costs more memory than it
+// saves time, so sparse maps hash each direction instead.
+const CELLS_PER_ASTEROID: usize = 64;
+
+impl Offsets {
+    // None when the map is too sparse for the table to pay its way
+    fn for_map(asteroids: &[Loc]) -> Option<Offsets> {
+        let (width, height) = Offsets::extent(asteroids);
+        let cells = (2 * width as usize + 1) * (2 * height as usize + 1);
+        let budget = (CELLS_PER_ASTEROID * asteroids.len()).max(1 << 16);
+        if cells > budget || cells >= NONE as usize {
+            return None;
+        }
+        Some(Offsets::new(asteroids))
+    }
+
+    fn new(asteroids: &[Loc]) -> Offsets {
+        let (width, height) = Offsets::extent(asteroids);
+        let mut offsets = Offsets {
+            reduced: Vec::with_capacity(((2 * width + 1) * (2 * height + 1)) as usize),
+            width,
+            height,
+        };
+        for dy in -height..=height {
+            for dx in -width..=width {
+                let cell = match Direction::new(dx, dy) {
+                    Some(direction) => offsets.cell(direction.dx, direction.dy) as u32,
+                    None => NONE,
+                };
+                offsets.reduced.push(cell);
+            }
+        }
+        offsets
+    }
+
+    fn extent(asteroids: &[Loc]) -> (i32, i32) {
+        let width = asteroids.iter().map(|loc| loc.x).max().unwrap_or(0);
+        let height = asteroids.iter().map(|loc| loc.y).max().unwrap_or(0);
+        (width, height)
+    }
+
+    fn cell(&self, dx: i32, dy: i32) -> usize {
+        ((dy + self.height) * (2 * self.width + 1) + dx + self.width) as usize
+    }
+
+    fn len(&self) -> usize {
+        self.reduced.len()
+    }
+}
+
+// keeps the earlier of two equally good stations
+fn better<'a>(best: Option<(usize, &'a Loc)>, candidate: (usize, &'a Loc)) -> Option<(usize, &'a Loc)> {
+    match best {
+        Some(best) if best.0 >= candidate.0 => Some(best),
+        _ => Some(candidate),
+    }
+}
+
+// The steps k for which start + k * step is in 0..size, or None if it never
+// is. A zero step stays put, so that's every k or none.
+fn steps_inside(start: i32, step: i32, size: usize) -> Option<(i64, i64)> {
+    let (start, step, last) = (start as i64, step as i64, size as i64 - 1);
+    let ceil_div = |a: i64, b: i64| -((-a).div_euclid(b));
+    let (first, last) = match step.signum() {
+        0 if (0..=last).contains(&start) => (i64::MIN, i64::MAX),
+        0 => return None,
+        1 => (ceil_div(-start, step), (last - start).div_euclid(step)),
+        _ => (ceil_div(start - last, -step), start.div_euclid(-step)),
+    };
+    if first <= last {
+        Some((first, last))
+    } else {
+        None
+    }
+}
+
+impl Scout {
+    pub fn load(path: &str) -> Result<Scout, String> {
+        let input = fs::read_to_string(path).map_err(|err| err.to_string())?;
+        Scout::parse(&input)
+    }
+
+    // `#` for an asteroid and `.` for empty space, every row the same width.
+    // Blank lines at the end are ignored, but not in the middle of the map.
+    pub fn parse(input: &str) -> Result<Scout, String> {
+        let mut asteroids = Vec::new();
+        let mut width = None;
+        let mut height = 0;
+        let lines: Vec<&str> = input.lines().map(|line| line.trim_end_matches('\r')).collect();
+        let rows = lines.iter().rposition(|line| !line.trim().is_empty()).map_or(0, |last| last + 1);
+        for (i, line) in lines[..rows].iter().enumerate() {
+            if line.trim().is_empty() {
+                return Err(format!("line {} is blank", i + 1));
+            }
+            let row_width = line.chars().count();
+            match width {
+                None => width = Some(row_width),
+                Some(width) if width != row_width => {
+                    return Err(format!(
+                        "line {} is {} wide, but the map is {} wide",
+                        i + 1,
+                        row_width,
+                        width
+                    ))
+                }
+                _ => {}
+            }
+            for (j, c) in line.chars().enumerate() {
+                match c {
+                    '#' => asteroids.push(Loc { can_see: 0, x: j as i32, y: i as i32 }),
+                    '.' => {}
+                    _ => return Err(format!("line {}, column {}: unexpected {:?}", i + 1, j + 1, c)),
+                }
+            }
+            height += 1;
+        }
+        match width {
+            Some(width) if width > 0 => {
+                let grid = asteroids
+                    .iter()
+                    .enumerate()
+                    .map(|(i, asteroid)| ((asteroid.x, asteroid.y), i))
+                    .collect();
+                Ok(Scout { asteroids, width, height, grid })
+            }
+            _ => Err(String::from("the map is empty")),
+        }
+    }
+
+    // every asteroid, in map order
+    pub fn asteroids(&self) -> &[Loc] {
+        &self.asteroids
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    // the asteroid at (x, y), if there is one; anywhere off the map is empty
+    pub fn get(&self, x: i32, y: i32) -> Option<&Loc> {
+        let i = self.grid.get(&(x, y))?;
+        Some(&self.asteroids[*i])
+    }
+
+    // the asteroids `loc` can see, clockwise from straight up
+    pub fn visible_from(&self, loc: &Loc) -> Vec<&Loc> {
+        self.count_unique_angles(loc)
+            .into_values()
+            .map(|ray| ray[0])
+            .collect()
+    }
+
+    // whether the line between the two is clear; nothing sees itself
+    pub fn is_visible(&self, a: &Loc, b: &Loc) -> bool {
+        a != b && self.blockers_between(a, b).is_empty()
+    }
+
+    // The asteroids strictly between `a` and `b`, nearest `a` first. Only
+    // the grid points on the line can hold one, so this walks those rather
+    // than the whole map.
+    pub fn blockers_between(&self, a: &Loc, b: &Loc) -> Vec<&Loc> {
+        let direction = match Direction::new(b.x - a.x, b.y - a.y) {
+            Some(direction) => direction,
+            None => return Vec::new(),
+        };
+        let (mut x, mut y) = (a.x + direction.dx, a.y + direction.dy);
+        let mut blockers = Vec::new();
+        while (x, y) != (b.x, b.y) {
+            blockers.extend(self.get(x, y));
+            x += direction.dx;
+            y += direction.dy;
+        }
+        blockers
+    }
+
+    // The first asteroid looking out from `loc` along `direction`, which
+    // needn't be reduced: (2, 0) looks the same way as (1, 0). `loc` can be
+    // off the map, in which case the look starts where the ray comes onto it.
+    pub fn nearest_in_direction(&self, loc: &Loc, direction: Direction) -> Option<&Loc> {
+        let step = Direction::new(direction.dx, direction.dy)?;
+        let (first_x, last_x) = steps_inside(loc.x, step.dx, self.width)?;
+        let (first_y, last_y) = steps_inside(loc.y, step.dy, self.height)?;
+        (first_x.max(first_y).max(1)..=last_x.min(last_y)).find_map(|k| {
+            let x = loc.x as i64 + k * step.dx as i64;
+            let y = loc.y as i64 + k * step.dy as i64;
+            self.get(x as i32, y as i32)
+        })
+    }
+
+    pub fn vaporization(&self, station: &Loc, start: Direction, sweep: Sweep) -> Vaporization<'_> {
+        Vaporization::new(self.count_unique_angles(station), start, sweep)
+    }
+
+    // The asteroid that can see the most others. Each asteroid only needs a
+    // set of directions, so this is O(n²) overall, shared out between threads.
+    pub fn find_station(&self) -> Loc {
+        let threads = thread::available_parallelism().map_or(1, |n| n.get());
+        self.find_station_with(threads)
+    }
+
+    fn find_station_with(&self, threads: usize) -> Loc {
+        // the first of the best, in map order, whatever the thread count
+        let best = self
+            .visibility_with(threads)
+            .into_iter()
+            .zip(&self.asteroids)
+            .fold(None, better);
+        match best {
+            Some((can_see, asteroid)) => Loc {
+                can_see,
+                x: asteroid.x,
+                y: asteroid.y,
+            },
+            None => Loc { can_see: 0, x: 0, y: 0 },
+        }
+    }
+
+    // how many others each asteroid can see, in map order
+    pub fn visibility(&self) -> Vec<usize> {
+        let threads = thread::available_parallelism().map_or(1, |n| n.get());
+        self.visibility_with(threads)
+    }
+
+    fn visibility_with(&self, threads: usize) -> Vec<usize> {
+        self.visibility_using(threads, Offsets::for_map(&self.asteroids).as_ref())
+    }
+
+    fn visibility_using(&self, threads: usize, offsets: Option<&Offsets>) -> Vec<usize> {
+        let chunk = self.asteroids.len().div_ceil(threads.max(1)).max(1);
+        thread::scope(|scope| {
+            let workers: Vec<_> = self
+                .asteroids
+                .chunks(chunk)
+                .map(|chunk| {
+                    scope.spawn(move || match offsets {
+                        Some(offsets) => {
+                            // the asteroid each direction was last seen from,
+                            // so it never needs clearing
+                            let mut seen = vec![NONE; offsets.len()];
+                            chunk
+                                .iter()
+                                .enumerate()
+                                .map(|(i, asteroid)| self.count_visible(asteroid, i as u32, offsets, &mut seen))
+                                .collect::<Vec<usize>>()
+                        }
+                        None => {
+                            let mut seen = HashSet::new();
+                            chunk
+                                .iter()
+                                .map(|asteroid| self.count_directions(asteroid, &mut seen))
+                                .collect()
+                        }
+                    })
+                })
+                .collect();
+            workers
+                .into_iter()
+                .flat_map(|worker| worker.join().unwrap())
+                .collect()
+        })
+    }
+
+    pub fn heatmap(&self) -> Heatmap {
+        let counts = self.visibility();
+        let best = counts
+            .iter()
+            .cloned()
+            .zip(&self.asteroids)
+            .fold(None, better)
+            .map(|(_, asteroid)| asteroid);
+        Heatmap::new(self.width, self.height, &self.asteroids, &counts, best)
+    }
+
+    fn count_visible(&self, asteroid: &Loc, id: u32, offsets: &Offsets, seen: &mut [u32]) -> usize {
+        let mut count = 0;
+        for other in &self.asteroids {
+            let cell = offsets.reduced[offsets.cell(other.x - asteroid.x, other.y - asteroid.y)];
+            if cell != NONE && seen[cell as usize] != id {
+                seen[cell as usize] = id;
+                count += 1;
+            }
+        }
+        count
+    }
+
+    // the same without the table, for maps too sparse to have one
+    fn count_directions(&self, asteroid: &Loc, seen: &mut HashSet<Direction>) -> usize {
+        seen.clear();
+        for other in &self.asteroids {
+            if let Some(direction) = Direction::new(other.x - asteroid.x, other.y - asteroid.y) {
+                seen.insert(direction);
+            }
+        }
+        seen.len()
+    }
+
+    // every other asteroid by its direction from this one, nearest first
+    fn count_unique_angles(&self, asteroid: &Loc) -> BTreeMap<Direction, Vec<&Loc>> {
+        let mut uniq = BTreeMap::new();
+        for other in &self.asteroids {
+            if let Some(key) = Direction::new(other.x - asteroid.x, other.y - asteroid.y) {
+                uniq.entry(key).or_insert_with(Vec::new).push(other);
+            }
+        }
+        for locs in uniq.values_mut() {
+            locs.sort_by_key(|loc| loc.distance(asteroid));
+        }
+        uniq
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_station() {
+        let scout = Scout::parse(".#..#\n.....\n#####\n....#\n...##\n").unwrap();
+        let station = scout.find_station();
+        assert_eq!((station.x, station.y, station.can_see), (3, 4, 8));
+    }
+
+    #[test]
+    fn test_vaporize() {
+        let scout = Scout::parse(
+            ".#....#####...#..\n\
+             ##...##.#####..##\n\
+             ##...#...#.#####.\n\
+             ..#.....#...###..\n\
+             ..#.#.....#....##\n",
+        )
+        .unwrap();
+        let station = Loc { can_see: 0, x: 8, y: 3 };
+        let vaporized: Vec<(i32, i32)> = scout
+            .vaporization(&station, Direction::UP, Sweep::Clockwise)
+            .map(|loc| (loc.x, loc.y))
+            .collect();
+        assert_eq!(
+            vaporized[..9].to_vec(),
+            vec![(8, 1), (9, 0), (9, 1), (10, 0), (9, 2), (11, 1), (12, 1), (11, 2), (15, 1)]
+        );
+        assert_eq!(vaporized.len(), 36);
+        assert_eq!(vaporized[35], (14, 3));
+
+        for n in 0..40 {
+            let mut laser = scout.vaporization(&station, Direction::UP, Sweep::Clockwise);
+            let nth = laser.nth(n).map(|loc| (loc.x, loc.y));
+            assert_eq!(nth, vaporized.get(n).cloned());
+            let after = laser.next().map(|loc| (loc.x, loc.y));
+            assert_eq!(after, vaporized.get(n + 1).cloned());
+        }
+    }
+
+    #[test]
+    fn test_sweeps() {
+        // a plus sign around the station, two deep to the right
+        let scout = Scout::parse(".#...\n#.###\n.#...\n").unwrap();
+        let station = Loc { can_see: 0, x: 1, y: 1 };
+        let order = |start: Direction, sweep: Sweep| -> Vec<(i32, i32)> {
+            scout
+                .vaporization(&station, start, sweep)
+                .map(|loc| (loc.x, loc.y))
+                .collect()
+        };
+        let left = Direction::new(-1, 0).unwrap();
+        assert_eq!(
+            order(Direction::UP, Sweep::Clockwise),
+            vec![(1, 0), (2, 1), (1, 2), (0, 1), (3, 1), (4, 1)]
+        );
+        assert_eq!(
+            order(Direction::UP, Sweep::CounterClockwise),
+            vec![(1, 0), (0, 1), (1, 2), (2, 1), (3, 1), (4, 1)]
+        );
+        assert_eq!(
+            order(left, Sweep::Clockwise),
+            vec![(0, 1), (1, 0), (2, 1), (1, 2), (3, 1), (4, 1)]
+        );
+        // between up and right: the laser comes round to up last
+        let up_right = Direction::new(1, -1).unwrap();
+        assert_eq!(
+            order(up_right, Sweep::Clockwise),
+            vec![(2, 1), (1, 2), (0, 1), (1, 0), (3, 1), (4, 1)]
+        );
+        assert_eq!(
+            order(up_right, Sweep::CounterClockwise),
+            vec![(1, 0), (0, 1), (1, 2), (2, 1), (3, 1), (4, 1)]
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let scout = Scout::parse("#.#\n...\n").unwrap();
+        assert_eq!((scout.asteroids.len(), scout.width, scout.height), (2, 3, 2));
+        assert_eq!(
+            Scout::parse("#.#\n..\n#..\n").err(),
+            Some(String::from("line 2 is 2 wide, but the map is 3 wide"))
+        );
+        assert_eq!(
+            Scout::parse("#.#\n.x.\n").err(),
+            Some(String::from("line 2, column 2: unexpected 'x'"))
+        );
+        assert_eq!(Scout::parse("").err(), Some(String::from("the map is empty")));
+        assert_eq!(Scout::parse("\n\n").err(), Some(String::from("the map is empty")));
+
+        // blank lines after the map are fine, but not inside it
+        let scout = Scout::parse("#.#\n...\n\n\r\n").unwrap();
+        assert_eq!((scout.width, scout.height), (3, 2));
+        assert_eq!(Scout::parse("#.#\n\n...\n").err(), Some(String::from("line 2 is blank")));
+        assert!(Scout::load("no such map.txt").is_err());
+    }
+
+    #[test]
+    fn test_threads_agree() {
+        let scout = Scout::parse(&bench::generate(600, 7)).unwrap();
+        let one = scout.find_station_with(1);
+        for threads in &[2, 3, 8] {
+            let station = scout.find_station_with(*threads);
+            assert_eq!((station.x, station.y, station.can_see), (one.x, one.y, one.can_see));
+        }
+    }
+
+    #[test]
+    fn test_sparse_maps() {
+        // both ways of counting agree on a dense map
+        let scout = Scout::parse(&bench::generate(600, 7)).unwrap();
+        let table = Offsets::for_map(&scout.asteroids).unwrap();
+        assert_eq!(scout.visibility_using(3, Some(&table)), scout.visibility_using(3, None));
+
+        // a few asteroids far apart get no table, which would need
+        // (2 * 1999 + 1)² cells
+        let scout = Scout::parse(&bench::generate_sparse(40, 100_000, 11)).unwrap();
+        assert_eq!(scout.width(), 2000);
+        assert!(Offsets::for_map(&scout.asteroids).is_none());
+        let counts = scout.visibility();
+        for (asteroid, count) in scout.asteroids.iter().zip(counts) {
+            assert_eq!(scout.visible_from(asteroid).len(), count);
+        }
+    }
+}
@@ -1,197 +1,70 @@
-use std::f64;
-use std::collections::HashMap;
-use std::cmp::{Eq, PartialOrd, Ord, Ordering};
-use std::hash::{Hash, Hasher};
-
-const MAP: &str = "\
-.#.####..#.#...#...##..#.#.##.
-..#####.##..#..##....#..#...#.
-......#.......##.##.#....##..#
-..#..##..#.###.....#.#..###.#.
-..#..#..##..#.#.##..###.......
-...##....#.##.#.#..##.##.#...#
-.##...#.#.##..#.#........#.#..
-.##...##.##..#.#.##.#.#.#.##.#
-#..##....#...###.#..##.#...##.
-.###.###..##......#..#...###.#
-.#..#.####.#..#....#.##..#.#.#
-..#...#..#.#######....###.....
-####..#.#.#...##...##....#..##
-##..#.##.#.#..##.###.#.##.##..
-..#.........#.#.#.#.......#..#
-...##.#.....#.#.##........#..#
-##..###.....#.............#.##
-.#...#....#..####.#.#......##.
-..#..##..###...#.....#...##..#
-...####..#.#.##..#....#.#.....
-####.#####.#.#....#.#....##.#.
-#.#..#......#.........##..#.#.
-#....##.....#........#..##.##.
-.###.##...##..#.##.#.#...#.#.#
-##.###....##....#.#.....#.###.
-..#...#......#........####..#.
-#....#.###.##.#...#.#.#.#.....
-.........##....#...#.....#..##
-###....#.........#..#..#.#.#..
-##...#...###.#..#.###....#.##.
-";
-
-#[derive(Debug)]
-struct Loc {
-    can_see: usize,
-    x: i32,
-    y: i32,
-}
-
-impl Loc {
-    fn distance(&self, other: &Loc) -> i32 {
-        (other.x - self.x).abs() + (other.y - self.y).abs()
-    }
-}
-
-impl PartialEq for Loc {
-    fn eq(&self, other: &Loc) -> bool {
-        self.x == other.x && self.y == other.y
-    }
-}
-
-impl Eq for Loc {}
-
-struct Scout {
-    asteroids: Vec<Loc>,
-}
-
-#[derive(Debug, Copy, Clone)]
-struct Float(f64);
-
-impl Float {
-    fn canonicalize(&self) -> i64 {
-        (self.0 * 1024.0 * 1024.0).round() as i64
+use std::env;
+use std::fs::File;
+use std::io::BufWriter;
+
+use day10::bench;
+use day10::direction::Direction;
+use day10::laser::Sweep;
+use day10::Scout;
+
+// usage: day10 [--map FILE] [--start DX,DY] [--counter-clockwise]
+//              [--heatmap ansi|FILE.ppm] [--scale N]
+//        day10 bench [ASTEROIDS] [SPARSITY]
+fn main() {
+    let args: Vec<String> = env::args().collect();
+    if args.get(1).map(String::as_str) == Some("bench") {
+        let asteroids = args.get(2).map_or(20_000, |n| n.parse().unwrap());
+        // one cell in SPARSITY holds an asteroid, 3 by default
+        let sparsity = args.get(3).map_or(3, |n| n.parse().unwrap());
+        bench::run(asteroids, sparsity);
+        return;
     }
-}
 
-impl PartialEq for Float {
-    fn eq(&self, other: &Float) -> bool {
-        self.canonicalize() == other.canonicalize()
-    }
-}
-
-impl Ord for Float {
-    fn cmp(&self, other: &Float) -> Ordering {
-        if self.0 < other.0 {
-            Ordering::Less
-        } else if self.0 > other.0 {
-            Ordering::Greater
-        } else {
-            Ordering::Equal
+    let option = |name: &str| {
+        let i = args.iter().position(|arg| arg == name)?;
+        args.get(i + 1).cloned()
+    };
+    let path = option("--map").unwrap_or_else(|| String::from("data.txt"));
+    let scout = match Scout::load(&path) {
+        Ok(scout) => scout,
+        Err(err) => {
+            eprintln!("{}: {}", path, err);
+            std::process::exit(1);
         }
-    }
-}
+    };
 
-impl PartialOrd for Float {
-    fn partial_cmp(&self, other: &Float) -> Option<Ordering> {
-        Some(self.cmp(other))
-    }
-}
-
-impl Eq for Float {}
-
-impl Hash for Float {
-    fn hash<H>(&self, state: &mut H) where H: Hasher {
-        self.canonicalize().hash(state);
-    }
-}
-
-fn main() {
     // part 1
-    let scout = Scout::new(&MAP);
     let station = scout.find_station();
     println!("{:?}", station);
 
-    // part 2
-    let vaporize = scout.vaporize(&station);
-    println!("{:?}", vaporize[199]);
-}
-
-impl Scout {
-    fn new(input: &str) -> Scout {
-        let mut asteroids = Vec::new();
-        for (i, line) in input.lines().enumerate() {
-            for (j, c) in line.chars().enumerate() {
-                if c == '#' {
-                    asteroids.push(Loc { can_see: 0, x: j as i32, y: i as i32 });
-                }
-            }
-        }
-        Scout { asteroids }
-    }
-
-    fn vaporize(&self, start: &Loc) -> Vec<&Loc> {
-        let mut results = Vec::new();
-        let angles = self.count_unique_angles(start);
-        let mut collected: Vec<&Float> = angles.keys().collect();
-        collected.sort();
-        collected.reverse();
-        let mut change = true;
-        while change {
-            change = false;
-            for angle in &collected {
-                if let Some(locs) = angles.get(angle) {
-                    for loc in locs {
-                        if !results.contains(loc) {
-                            change = true;
-                            results.push(loc);
-                            break;
-                        }
-                    }
-                }
-            }
-        }
-        results
-    }
-
-    fn find_station(&self) -> Loc {
-        let mut best = Loc {
-            can_see: 0,
-            x: 0,
-            y: 0,
-        };
-        for asteroid in &self.asteroids {
-            let can_see = self.count_unique_angles(&asteroid).keys().count();
-            // println!("> asteroid {:?} can see {}", asteroid, can_see);
-            if can_see > best.can_see {
-                best.can_see = can_see;
-                best.x = asteroid.x;
-                best.y = asteroid.y;
+    if let Some(target) = option("--heatmap") {
+        let heatmap = scout.heatmap();
+        if target == "ansi" {
+            print!("{}", heatmap.ansi());
+        } else {
+            let scale = option("--scale").map_or(8, |n| n.parse().unwrap());
+            if scale == 0 {
+                eprintln!("--scale must be at least 1");
+                std::process::exit(1);
             }
+            let mut out = BufWriter::new(File::create(&target).unwrap());
+            heatmap.write_ppm(&mut out, scale).unwrap();
         }
-        best
     }
 
-    fn count_unique_angles(&self, asteroid: &Loc) -> HashMap<Float, Vec<&Loc>> {
-        let mut uniq = HashMap::new();
-        for other in &self.asteroids {
-            if asteroid.x != other.x || asteroid.y != other.y {
-                let x = (asteroid.x - other.x) as f64;
-                let y = (asteroid.y - other.y) as f64;
-                let mut key = Float(x.atan2(y));
-                if key.0 > 0.0 {
-                    key.0 -= 4.0 * f64::consts::FRAC_PI_2;
-                }
-                let e = uniq.entry(key).or_insert(vec![]);
-                // if asteroid.x == 2 && asteroid.y == 2 {
-                //     println!("{:?} ({}, {}): {:?}", other, x, y, key);
-                // }
-                e.push(other);
-                e.sort_by(|a, b| a.distance(asteroid).cmp(&b.distance(asteroid)));
-            }
+    // part 2
+    let start = match option("--start") {
+        Some(start) => {
+            let offset: Vec<i32> = start.split(',').map(|n| n.trim().parse().unwrap()).collect();
+            Direction::new(offset[0], offset[1]).expect("the laser needs a direction to start in")
         }
-        // if asteroid.x == 2 && asteroid.y == 2 {
-        //     let mut keys: Vec<&Float> = uniq.keys().collect();
-        //     keys.sort();
-        //     keys.reverse();
-        //     println!("{:?}", keys);
-        // }
-        uniq
-    }
+        None => Direction::UP,
+    };
+    let sweep = if args.iter().any(|arg| arg == "--counter-clockwise") {
+        Sweep::CounterClockwise
+    } else {
+        Sweep::Clockwise
+    };
+    let mut laser = scout.vaporization(&station, start, sweep);
+    println!("{:?}", laser.nth(199));
 }
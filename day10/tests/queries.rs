@@ -0,0 +1,79 @@
+use day10::bench;
+use day10::direction::Direction;
+use day10::{Loc, Scout};
+
+const EXAMPLE: &str = ".#..#\n.....\n#####\n....#\n...##\n";
+
+fn places(locs: &[&Loc]) -> Vec<(i32, i32)> {
+    locs.iter().map(|loc| (loc.x, loc.y)).collect()
+}
+
+#[test]
+fn test_visible_from() {
+    let scout = Scout::parse(EXAMPLE).unwrap();
+    let corner = scout.get(1, 0).unwrap();
+    assert_eq!(
+        places(&scout.visible_from(corner)),
+        vec![(4, 0), (4, 2), (3, 2), (4, 4), (2, 2), (1, 2), (0, 2)]
+    );
+    assert_eq!(scout.visible_from(&scout.find_station()).len(), 8);
+}
+
+#[test]
+fn test_blockers() {
+    let scout = Scout::parse(EXAMPLE).unwrap();
+    let corner = Loc::new(1, 0);
+    let station = Loc::new(3, 4);
+    assert_eq!(places(&scout.blockers_between(&corner, &station)), vec![(2, 2)]);
+    assert!(!scout.is_visible(&corner, &station));
+    assert!(!scout.is_visible(&station, &corner));
+    assert!(!scout.is_visible(&station, &station));
+    assert!(scout.is_visible(&station, &Loc::new(4, 4)));
+
+    assert_eq!(
+        places(&scout.blockers_between(&Loc::new(4, 0), &Loc::new(4, 4))),
+        vec![(4, 2), (4, 3)]
+    );
+    // from off the map, looking in
+    assert_eq!(
+        places(&scout.blockers_between(&Loc::new(-1, 2), &Loc::new(4, 2))),
+        vec![(0, 2), (1, 2), (2, 2), (3, 2)]
+    );
+}
+
+#[test]
+fn test_nearest_in_direction() {
+    let scout = Scout::parse(EXAMPLE).unwrap();
+    let station = Loc::new(3, 4);
+    let nearest = |dx, dy| scout.nearest_in_direction(&station, Direction { dx, dy }).map(|loc| (loc.x, loc.y));
+    assert_eq!(nearest(0, -1), Some((3, 2)));
+    assert_eq!(nearest(0, -4), Some((3, 2)));
+    assert_eq!(nearest(1, 0), Some((4, 4)));
+    assert_eq!(nearest(-1, 0), None);
+    assert_eq!(nearest(-1, -1), Some((1, 2)));
+    assert_eq!(nearest(0, 0), None);
+
+    // from off the map, looking in
+    let look = |x, y, dx, dy| scout.nearest_in_direction(&Loc::new(x, y), Direction { dx, dy }).map(|loc| (loc.x, loc.y));
+    assert_eq!(look(-2, 2, 1, 0), Some((0, 2)));
+    assert_eq!(look(-2, 2, -1, 0), None);
+    assert_eq!(look(4, 10, 0, -1), Some((4, 4)));
+    assert_eq!(look(-3, -4, 1, 1), Some((1, 0)));
+    assert_eq!(look(-1, 0, 0, 1), None);
+    assert_eq!(look(i32::MIN, 0, 1, 0), Some((1, 0)));
+}
+
+#[test]
+fn test_queries_agree_with_counts() {
+    let scout = Scout::parse(&bench::generate(150, 3)).unwrap();
+    let counts = scout.visibility();
+    for (asteroid, count) in scout.asteroids().iter().zip(counts) {
+        assert_eq!(scout.visible_from(asteroid).len(), count);
+        let clear = scout
+            .asteroids()
+            .iter()
+            .filter(|other| scout.is_visible(asteroid, other))
+            .count();
+        assert_eq!(clear, count);
+    }
+}
@@ -0,0 +1,110 @@
+use std::io::{self, Write};
+use std::thread;
+use std::time::Duration;
+
+use crate::hull::{Bounds, Hull, Paint};
+
+// Redraws the hull in the terminal after every robot instruction: `#` white,
+// `.` black, the robot as an arrow showing where it's heading and the panel
+// it just painted in reverse video. The view only ever grows, so the picture
+// doesn't jump around as the robot wanders.
+pub struct Animation<W: Write> {
+    out: W,
+    delay: Option<Duration>,
+    view: Option<Bounds>,
+    step: usize,
+}
+
+impl<W: Write> Animation<W> {
+    // None draws frames as fast as it can
+    pub fn new(out: W, fps: Option<f64>) -> Animation<W> {
+        Animation {
+            out,
+            delay: fps.map(|fps| Duration::from_secs_f64(1.0 / fps)),
+            view: None,
+            step: 0,
+        }
+    }
+
+    pub fn into_inner(self) -> W {
+        self.out
+    }
+
+    // every panel is painted from where the robot stood, so following the
+    // robot is enough to keep the whole hull in view
+    fn grow_view(&mut self, x: i64, y: i64) -> Bounds {
+        let here = Bounds::point(x, y);
+        let view = self.view.map_or(here, |view| view.union(here));
+        self.view = Some(view);
+        view
+    }
+
+    pub fn draw(
+        &mut self,
+        hull: &Hull,
+        robot: (i64, i64),
+        arrow: char,
+        fresh: Option<(i64, i64)>,
+    ) -> io::Result<()> {
+        let view = self.grow_view(robot.0, robot.1);
+        let mut text = String::from("\x1b[H\x1b[2J");
+        for y in (view.min_y..=view.max_y).rev() {
+            for x in view.min_x..=view.max_x {
+                let symbol = if (x, y) == robot {
+                    arrow
+                } else {
+                    match hull.get(x, y) {
+                        Paint::White => '#',
+                        Paint::Black => '.',
+                        Paint::Unpainted => ' ',
+                    }
+                };
+                if Some((x, y)) == fresh {
+                    text.push_str(&format!("\x1b[7m{}\x1b[0m", symbol));
+                } else {
+                    text.push(symbol);
+                }
+            }
+            text.push('\n');
+        }
+        text.push_str(&format!(
+            "step {} | robot at ({}, {}) heading {} | panels painted: {}\n",
+            self.step,
+            robot.0,
+            robot.1,
+            arrow,
+            hull.painted()
+        ));
+        self.out.write_all(text.as_bytes())?;
+        self.out.flush()?;
+        self.step += 1;
+        if let Some(delay) = self.delay {
+            thread::sleep(delay);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_frames() {
+        let mut hull = Hull::new();
+        let mut animation = Animation::new(Vec::new(), None);
+        animation.draw(&hull, (0, 0), '^', None).unwrap();
+        hull.paint(0, 0, Paint::White);
+        animation.draw(&hull, (0, 0), '^', Some((0, 0))).unwrap();
+        animation.draw(&hull, (-1, 0), '<', None).unwrap();
+        animation.draw(&hull, (-1, 0), 'v', None).unwrap();
+
+        let text = String::from_utf8(animation.into_inner()).unwrap();
+        let frames: Vec<&str> = text.split("\x1b[H\x1b[2J").skip(1).collect();
+        assert_eq!(frames.len(), 4);
+        assert_eq!(frames[0], "^\nstep 0 | robot at (0, 0) heading ^ | panels painted: 0\n");
+        assert!(frames[1].starts_with("\x1b[7m^\x1b[0m\n"));
+        assert!(frames[2].starts_with("<#\n"));
+        assert!(frames[3].starts_with("v#\n"));
+    }
+}
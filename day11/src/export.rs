@@ -0,0 +1,248 @@
+use std::io::{self, Write};
+
+use crate::hull::{Hull, Paint};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Palette {
+    pub white: [u8; 3],
+    pub black: [u8; 3],
+    pub unpainted: [u8; 3],
+}
+
+impl Palette {
+    pub fn color(&self, paint: Paint) -> [u8; 3] {
+        match paint {
+            Paint::White => self.white,
+            Paint::Black => self.black,
+            Paint::Unpainted => self.unpainted,
+        }
+    }
+}
+
+impl Default for Palette {
+    fn default() -> Palette {
+        Palette {
+            white: [255, 255, 255],
+            black: [0, 0, 0],
+            unpainted: [40, 60, 90],
+        }
+    }
+}
+
+// The hull cropped to its bounding box, top row first, the way it reads when
+// the robot's up is up. An empty hull is a single unpainted panel.
+fn grid(hull: &Hull) -> Vec<Vec<Paint>> {
+    match hull.bounds() {
+        Some(bounds) => (bounds.min_y..=bounds.max_y)
+            .rev()
+            .map(|y| (bounds.min_x..=bounds.max_x).map(|x| hull.get(x, y)).collect())
+            .collect(),
+        None => vec![vec![Paint::Unpainted]],
+    }
+}
+
+// a scale of 0 would make an image with no pixels at all
+fn check_scale(scale: usize) -> io::Result<()> {
+    if scale == 0 {
+        return Err(io::Error::new(io::ErrorKind::InvalidInput, "scale must be at least 1"));
+    }
+    Ok(())
+}
+
+// each panel as a `scale` by `scale` block, row by row
+fn pixels(hull: &Hull, scale: usize) -> Vec<Vec<Paint>> {
+    let mut pixels = Vec::new();
+    for row in grid(hull) {
+        let line: Vec<Paint> = row
+            .iter()
+            .flat_map(|paint| std::iter::repeat_n(*paint, scale))
+            .collect();
+        for _ in 0..scale {
+            pixels.push(line.clone());
+        }
+    }
+    pixels
+}
+
+// Binary PBM only has black and white, so unpainted panels come out black,
+// like the black paint they look like on the hull.
+pub fn write_pbm<W: Write>(out: &mut W, hull: &Hull, scale: usize) -> io::Result<()> {
+    check_scale(scale)?;
+    let pixels = pixels(hull, scale);
+    writeln!(out, "P4\n{} {}", pixels[0].len(), pixels.len())?;
+    for row in pixels {
+        // eight pixels to a byte, most significant first, 1 for black
+        for chunk in row.chunks(8) {
+            let mut byte = 0;
+            for (i, paint) in chunk.iter().enumerate() {
+                if *paint != Paint::White {
+                    byte |= 0x80 >> i;
+                }
+            }
+            out.write_all(&[byte])?;
+        }
+    }
+    Ok(())
+}
+
+pub fn write_svg<W: Write>(out: &mut W, hull: &Hull, palette: &Palette, scale: usize) -> io::Result<()> {
+    check_scale(scale)?;
+    let grid = grid(hull);
+    let (width, height) = (grid[0].len() * scale, grid.len() * scale);
+    writeln!(
+        out,
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\" viewBox=\"0 0 {} {}\">",
+        width, height, width, height
+    )?;
+    writeln!(
+        out,
+        "<rect width=\"{}\" height=\"{}\" fill=\"{}\"/>",
+        width,
+        height,
+        hex(palette.unpainted)
+    )?;
+    for (y, row) in grid.iter().enumerate() {
+        for (x, paint) in row.iter().enumerate() {
+            if *paint != Paint::Unpainted {
+                writeln!(
+                    out,
+                    "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" fill=\"{}\"/>",
+                    x * scale,
+                    y * scale,
+                    scale,
+                    scale,
+                    hex(palette.color(*paint))
+                )?;
+            }
+        }
+    }
+    writeln!(out, "</svg>")
+}
+
+fn hex([r, g, b]: [u8; 3]) -> String {
+    format!("#{:02x}{:02x}{:02x}", r, g, b)
+}
+
+// An 8-bit RGB PNG. The image data is zlib with uncompressed ("stored")
+// deflate blocks, which every reader accepts and needs no compressor.
+pub fn write_png<W: Write>(out: &mut W, hull: &Hull, palette: &Palette, scale: usize) -> io::Result<()> {
+    check_scale(scale)?;
+    let pixels = pixels(hull, scale);
+    let (width, height) = (pixels[0].len() as u32, pixels.len() as u32);
+
+    let mut raw = Vec::with_capacity(pixels.len() * (1 + width as usize * 3));
+    for row in &pixels {
+        raw.push(0); // no filter
+        for paint in row {
+            raw.extend_from_slice(&palette.color(*paint));
+        }
+    }
+
+    let mut zlib = vec![0x78, 0x01];
+    let mut blocks = raw.chunks(0xffff).peekable();
+    if blocks.peek().is_none() {
+        zlib.extend_from_slice(&[1, 0, 0, 0xff, 0xff]);
+    }
+    while let Some(block) = blocks.next() {
+        let last = blocks.peek().is_none();
+        let len = block.len() as u16;
+        zlib.push(last as u8);
+        zlib.extend_from_slice(&len.to_le_bytes());
+        zlib.extend_from_slice(&(!len).to_le_bytes());
+        zlib.extend_from_slice(block);
+    }
+    zlib.extend_from_slice(&adler32(&raw).to_be_bytes());
+
+    let mut header = Vec::new();
+    header.extend_from_slice(&width.to_be_bytes());
+    header.extend_from_slice(&height.to_be_bytes());
+    header.extend_from_slice(&[8, 2, 0, 0, 0]); // 8 bits per channel, RGB
+
+    out.write_all(b"\x89PNG\r\n\x1a\n")?;
+    chunk(out, b"IHDR", &header)?;
+    chunk(out, b"IDAT", &zlib)?;
+    chunk(out, b"IEND", &[])
+}
+
+fn chunk<W: Write>(out: &mut W, kind: &[u8; 4], data: &[u8]) -> io::Result<()> {
+    out.write_all(&(data.len() as u32).to_be_bytes())?;
+    out.write_all(kind)?;
+    out.write_all(data)?;
+    let crc = crc32(kind.iter().chain(data));
+    out.write_all(&crc.to_be_bytes())
+}
+
+fn crc32<'a>(bytes: impl Iterator<Item = &'a u8>) -> u32 {
+    let mut crc = !0u32;
+    for byte in bytes {
+        crc ^= *byte as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 { (crc >> 1) ^ 0xedb8_8320 } else { crc >> 1 };
+        }
+    }
+    !crc
+}
+
+fn adler32(bytes: &[u8]) -> u32 {
+    let (mut a, mut b) = (1u32, 0u32);
+    for byte in bytes {
+        a = (a + *byte as u32) % 65521;
+        b = (b + a) % 65521;
+    }
+    (b << 16) | a
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // an L of white with one black panel, drawn upwards from (0, 0)
+    fn hull() -> Hull {
+        let mut hull = Hull::new();
+        hull.paint(0, 0, Paint::White);
+        hull.paint(1, 0, Paint::White);
+        hull.paint(0, 1, Paint::White);
+        hull.paint(0, 2, Paint::Black);
+        hull
+    }
+
+    #[test]
+    fn test_pbm() {
+        let mut out = Vec::new();
+        write_pbm(&mut out, &hull(), 1).unwrap();
+        // top row first: the black panel, then the upright, then the foot
+        assert_eq!(out, b"P4\n2 3\n\xc0\x40\x00".to_vec());
+    }
+
+    #[test]
+    fn test_svg() {
+        let mut out = Vec::new();
+        write_svg(&mut out, &hull(), &Palette::default(), 10).unwrap();
+        let text = String::from_utf8(out).unwrap();
+        assert!(text.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"20\" height=\"30\""));
+        assert!(text.contains("<rect width=\"20\" height=\"30\" fill=\"#283c5a\"/>"));
+        assert!(text.contains("<rect x=\"0\" y=\"0\" width=\"10\" height=\"10\" fill=\"#000000\"/>"));
+        assert!(text.contains("<rect x=\"10\" y=\"20\" width=\"10\" height=\"10\" fill=\"#ffffff\"/>"));
+        assert_eq!(text.matches("<rect").count(), 5);
+    }
+
+    #[test]
+    fn test_png() {
+        let mut out = Vec::new();
+        write_png(&mut out, &hull(), &Palette::default(), 2).unwrap();
+        assert!(out.starts_with(b"\x89PNG\r\n\x1a\n\x00\x00\x00\x0dIHDR\x00\x00\x00\x04\x00\x00\x00\x06"));
+        assert!(out.ends_with(b"\x00\x00\x00\x00IEND\xae\x42\x60\x82"));
+        assert_eq!(adler32(b"Wikipedia"), 0x11e6_0398);
+    }
+
+    #[test]
+    fn test_zero_scale() {
+        let mut hull = Hull::new();
+        hull.paint(0, 0, Paint::White);
+        let mut out = Vec::new();
+        assert!(write_pbm(&mut out, &hull, 0).is_err());
+        assert!(write_svg(&mut out, &hull, &Palette::default(), 0).is_err());
+        assert!(write_png(&mut out, &hull, &Palette::default(), 0).is_err());
+        assert!(out.is_empty());
+    }
+}
@@ -0,0 +1,142 @@
+use std::collections::HashMap;
+use std::convert::TryFrom;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Paint {
+    Unpainted,
+    Black,
+    White,
+}
+
+// The smallest rectangle holding every painted panel, edges included. y grows
+// upwards, the way the robot moves.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Bounds {
+    pub min_x: i64,
+    pub max_x: i64,
+    pub min_y: i64,
+    pub max_y: i64,
+}
+
+impl Bounds {
+    pub fn point(x: i64, y: i64) -> Bounds {
+        Bounds { min_x: x, max_x: x, min_y: y, max_y: y }
+    }
+
+    // the smallest bounds holding both
+    pub fn union(self, other: Bounds) -> Bounds {
+        Bounds {
+            min_x: self.min_x.min(other.min_x),
+            max_x: self.max_x.max(other.max_x),
+            min_y: self.min_y.min(other.min_y),
+            max_y: self.max_y.max(other.max_y),
+        }
+    }
+
+    // None for bounds too wide to count in a usize
+    pub fn width(&self) -> Option<usize> {
+        span(self.min_x, self.max_x)
+    }
+
+    pub fn height(&self) -> Option<usize> {
+        span(self.min_y, self.max_y)
+    }
+}
+
+fn span(min: i64, max: i64) -> Option<usize> {
+    let cells = (max as i128 - min as i128).checked_add(1)?;
+    usize::try_from(cells).ok()
+}
+
+// Only the panels that have been painted are stored, so the robot can wander
+// as far as it likes in any direction. Rendering and exporting draw the whole
+// bounding box, though, so those only suit hulls of a sensible size.
+#[derive(Debug, Clone, Default)]
+pub struct Hull {
+    panels: HashMap<(i64, i64), Paint>,
+}
+
+impl Hull {
+    pub fn new() -> Hull {
+        Hull::default()
+    }
+
+    pub fn get(&self, x: i64, y: i64) -> Paint {
+        self.panels.get(&(x, y)).cloned().unwrap_or(Paint::Unpainted)
+    }
+
+    // returns whether the panel had never been painted before
+    pub fn paint(&mut self, x: i64, y: i64, paint: Paint) -> bool {
+        self.panels.insert((x, y), paint).is_none()
+    }
+
+    // the number of panels painted at least once
+    pub fn painted(&self) -> usize {
+        self.panels.len()
+    }
+
+    pub fn panels(&self) -> impl Iterator<Item = ((i64, i64), Paint)> + '_ {
+        self.panels.iter().map(|(pos, paint)| (*pos, *paint))
+    }
+
+    pub fn bounds(&self) -> Option<Bounds> {
+        self.panels
+            .keys()
+            .map(|&(x, y)| Bounds::point(x, y))
+            .reduce(Bounds::union)
+    }
+
+    // the bounding box as text, top row first, with `#` for white panels
+    pub fn render(&self) -> String {
+        let bounds = match self.bounds() {
+            Some(bounds) => bounds,
+            None => return String::new(),
+        };
+        let mut text = String::new();
+        for y in (bounds.min_y..=bounds.max_y).rev() {
+            for x in bounds.min_x..=bounds.max_x {
+                text.push(if self.get(x, y) == Paint::White { '#' } else { ' ' });
+            }
+            text.push('\n');
+        }
+        text
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_paint_anywhere() {
+        let mut hull = Hull::new();
+        assert_eq!(hull.bounds(), None);
+        assert!(hull.paint(0, 0, Paint::White));
+        assert!(hull.paint(-3, 2, Paint::Black));
+        assert!(!hull.paint(0, 0, Paint::Black));
+        assert!(hull.paint(-1_000_000, 2_000_000, Paint::White));
+        assert_eq!(hull.painted(), 3);
+        assert_eq!(hull.get(0, 0), Paint::Black);
+        assert_eq!(hull.get(5, 5), Paint::Unpainted);
+        let bounds = hull.bounds().unwrap();
+        assert_eq!(bounds, Bounds { min_x: -1_000_000, max_x: 0, min_y: 0, max_y: 2_000_000 });
+        assert_eq!((bounds.width(), bounds.height()), (Some(1_000_001), Some(2_000_001)));
+    }
+
+    #[test]
+    fn test_size_overflow() {
+        let bounds = Bounds { min_x: i64::MIN, max_x: i64::MAX, min_y: -1, max_y: 1 };
+        assert_eq!((bounds.width(), bounds.height()), (None, Some(3)));
+    }
+
+    #[test]
+    fn test_render() {
+        let mut hull = Hull::new();
+        hull.paint(-1, 1, Paint::White);
+        hull.paint(0, 0, Paint::Black);
+        hull.paint(1, 0, Paint::White);
+        let bounds = hull.bounds().unwrap();
+        assert_eq!((bounds.width(), bounds.height()), (Some(3), Some(2)));
+        assert_eq!(hull.render(), "#  \n  #\n");
+    }
+}
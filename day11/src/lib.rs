@@ -1,3 +1,8 @@
+pub mod animation;
+pub mod export;
+pub mod hull;
+pub mod robot;
+
 pub struct IntCode {
     memory: Vec<i64>,
     counter: usize,
@@ -16,6 +21,13 @@ pub enum IoOperation {
     Write(i64),
 }
 
+// Something a program talks to through its input and output instructions.
+pub trait Device {
+    fn read(&mut self) -> i64;
+
+    fn write(&mut self, val: i64);
+}
+
 #[derive(Debug)]
 enum Instruction {
     Add(Mode, Mode, Mode),
@@ -110,6 +122,16 @@ impl IntCode {
         output
     }
 
+    pub fn run_device<D: Device + ?Sized>(&mut self, device: &mut D) {
+        self.run_fn(|op| match op {
+            IoOperation::Read => device.read(),
+            IoOperation::Write(val) => {
+                device.write(val);
+                0
+            }
+        });
+    }
+
     pub fn run_fn<F>(&mut self, mut io: F)
     where
         F: FnMut(IoOperation) -> i64,
@@ -1,130 +1,94 @@
+use std::env;
+use std::fs::File;
+use std::io::{self, BufWriter, Stdout, Write};
+use std::process;
+
 mod input;
-use day11::{IntCode, IoOperation};
+use day11::animation::Animation;
+use day11::export::{self, Palette};
+use day11::hull::Paint;
+use day11::robot::Robot;
+use day11::{Device, IntCode, IoOperation};
 
+// usage: day11 [--pbm FILE] [--png FILE] [--svg FILE] [--scale N]
+//              [--animate 1|2] [--fps N]
 fn main() {
+    let args: Vec<String> = env::args().collect();
+    let option = |name: &str| {
+        let i = args.iter().position(|arg| arg == name)?;
+        args.get(i + 1).cloned()
+    };
+    let scale = option("--scale").map_or(8, |scale| scale.parse().unwrap());
+    if scale == 0 {
+        eprintln!("--scale must be at least 1");
+        process::exit(1);
+    }
+    // --fps 0 animates as fast as the terminal keeps up
+    let fps = option("--fps").map_or(30.0, |fps| fps.parse().unwrap());
+    let animation = |part: &str| {
+        if option("--animate").as_deref() == Some(part) {
+            Some(Animation::new(io::stdout(), Some(fps).filter(|fps| *fps > 0.0)))
+        } else {
+            None
+        }
+    };
 
     // part 1
 
-    let mut program = IntCode::new(&input::ORIGINAL);
-    program.biggen(10000);
     let mut robot = Robot::new();
-    program.run_fn(|op| {
-        match op {
-            IoOperation::Read => robot.current_color(),
-            IoOperation::Write(val) => robot.execute(val),
-        }
-    });
-    println!("{}", robot.panels_painted);
+    run(&mut robot, animation("1"));
+    let stats = robot.stats();
+    println!("{}", stats.panels_painted);
+    println!(
+        "({} repaints along a path of {} panels)",
+        stats.repaints, stats.path_length
+    );
 
     // part 2
 
-    let mut program = IntCode::new(&input::ORIGINAL);
-    program.biggen(10000);
-    let mut robot = Robot::new();
-    robot.panel[robot.x][robot.y] = Paint::White;
-    program.run_fn(|op| {
-        match op {
-            IoOperation::Read => robot.current_color(),
-            IoOperation::Write(val) => robot.execute(val),
-        }
-    });
-    for row in robot.panel.iter() {
-        for x in row.iter() {
-            if let Paint::White = x {
-                print!("#");
-            } else {
-                print!(" ");
-            }
-        }
-        println!();
+    let mut robot = Robot::new().start_color(Paint::White);
+    run(&mut robot, animation("2"));
+    print!("{}", robot.hull().render());
+    match ocr::recognize_text(&robot.hull().render(), '#') {
+        Ok(text) => println!("{}", text),
+        Err(e) => println!("can't read the hull: {}", e),
     }
-}
-
-#[derive(Clone, Copy)]
-enum Paint {
-    Unpainted,
-    Black,
-    White,
-}
-
-struct Robot {
-    panel: [[Paint; 100]; 100],
-    x: usize,
-    y: usize,
-    direction: Direction,
-    is_reading_color: bool,
-    panels_painted: i32,
-}
 
-impl Robot {
-    fn new() -> Robot {
-        Robot {
-            panel: [[Paint::Unpainted; 100]; 100],
-            x: 50,
-            y: 50,
-            direction: Direction::Up,
-            is_reading_color: true,
-            panels_painted: 0,
-        }
+    let palette = Palette::default();
+    if let Some(path) = option("--pbm") {
+        save(&path, |out| export::write_pbm(out, robot.hull(), scale));
     }
-
-    fn current_color(&mut self) -> i64 {
-        if let Paint::White = self.panel[self.x][self.y] {
-            1
-        } else {
-            0
-        }
+    if let Some(path) = option("--png") {
+        save(&path, |out| export::write_png(out, robot.hull(), &palette, scale));
     }
-
-    fn execute(&mut self, val: i64) -> i64 {
-        if self.is_reading_color {
-            if let Paint::Unpainted = self.panel[self.x][self.y] {
-                self.panels_painted += 1;
-            }
-            self.panel[self.x][self.y] = if val == 1 {
-                Paint::White
-            } else {
-                Paint::Black
-            };
-        } else {
-            self.direction = if val == 0 {
-                Direction::rotate_left(self.direction)
-            } else {
-                Direction::rotate_right(self.direction)
-            };
-            match self.direction {
-                Direction::Up => self.y += 1,
-                Direction::Down => self.y -= 1,
-                Direction::Right => self.x += 1,
-                Direction::Left => self.x -= 1,
-            };
-        }
-        self.is_reading_color = !self.is_reading_color;
-        0
+    if let Some(path) = option("--svg") {
+        save(&path, |out| export::write_svg(out, robot.hull(), &palette, scale));
     }
 }
 
-#[derive(PartialEq, Clone, Copy)]
-enum Direction {
-    Up, Left, Down, Right
-}
-
-impl Direction {
-    fn rotate_left(d: Direction) -> Direction {
-        match d {
-            Direction::Up => Direction::Left,
-            Direction::Down => Direction::Right,
-            Direction::Right => Direction::Up,
-            Direction::Left => Direction::Down,
-        }
+// runs the painting program with the robot, drawing every step if animated
+fn run(robot: &mut Robot, mut animation: Option<Animation<Stdout>>) {
+    let mut program = IntCode::new(&input::ORIGINAL);
+    program.biggen(10000);
+    match animation.as_mut() {
+        None => program.run_device(robot),
+        Some(animation) => program.run_fn(|op| match op {
+            IoOperation::Read => robot.read(),
+            IoOperation::Write(val) => {
+                robot.write(val);
+                animation
+                    .draw(robot.hull(), robot.position(), robot.facing().arrow(), robot.fresh())
+                    .unwrap();
+                0
+            }
+        }),
     }
+}
 
-    fn rotate_right(d: Direction) -> Direction {
-        match d {
-            Direction::Up => Direction::Right,
-            Direction::Down => Direction::Left,
-            Direction::Right => Direction::Down,
-            Direction::Left => Direction::Up,
-        }
-    }
+fn save<F>(path: &str, write: F)
+where
+    F: FnOnce(&mut BufWriter<File>) -> std::io::Result<()>,
+{
+    let mut out = BufWriter::new(File::create(path).unwrap());
+    write(&mut out).and_then(|_| out.flush()).unwrap();
 }
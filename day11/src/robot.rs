@@ -0,0 +1,215 @@
+use std::collections::HashMap;
+
+use crate::hull::{Hull, Paint};
+use crate::Device;
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Heading {
+    Up,
+    Left,
+    Down,
+    Right,
+}
+
+impl Heading {
+    pub fn arrow(self) -> char {
+        match self {
+            Heading::Up => '^',
+            Heading::Left => '<',
+            Heading::Down => 'v',
+            Heading::Right => '>',
+        }
+    }
+
+    pub fn left(self) -> Heading {
+        match self {
+            Heading::Up => Heading::Left,
+            Heading::Left => Heading::Down,
+            Heading::Down => Heading::Right,
+            Heading::Right => Heading::Up,
+        }
+    }
+
+    pub fn right(self) -> Heading {
+        match self {
+            Heading::Up => Heading::Right,
+            Heading::Right => Heading::Down,
+            Heading::Down => Heading::Left,
+            Heading::Left => Heading::Up,
+        }
+    }
+
+    // the next panel this way, with y growing upwards
+    pub fn step(self, (x, y): (i64, i64)) -> (i64, i64) {
+        match self {
+            Heading::Up => (x, y + 1),
+            Heading::Left => (x - 1, y),
+            Heading::Down => (x, y - 1),
+            Heading::Right => (x + 1, y),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Stroke {
+    pub position: (i64, i64),
+    pub paint: Paint,
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Stats {
+    // distinct panels the robot painted itself, not counting the start color
+    pub panels_painted: usize,
+    // paint jobs on panels that had been painted before
+    pub repaints: usize,
+    pub path_length: usize,
+}
+
+// The hull-painting robot as an IntCode device: the program reads the color
+// under the robot (0 black, 1 white) and writes pairs of a color to paint and
+// a turn to make (0 left, 1 right) before moving forward one panel.
+pub struct Robot {
+    hull: Hull,
+    position: (i64, i64),
+    heading: Heading,
+    painting: bool,
+    history: Vec<Stroke>,
+    paint_counts: HashMap<(i64, i64), usize>,
+    path_length: usize,
+}
+
+impl Robot {
+    pub fn new() -> Robot {
+        Robot {
+            hull: Hull::new(),
+            position: (0, 0),
+            heading: Heading::Up,
+            painting: true,
+            history: Vec::new(),
+            paint_counts: HashMap::new(),
+            path_length: 0,
+        }
+    }
+
+    // the color of the panel the robot starts on
+    pub fn start_color(mut self, paint: Paint) -> Robot {
+        if paint != Paint::Unpainted {
+            self.hull.paint(self.position.0, self.position.1, paint);
+        }
+        self
+    }
+
+    pub fn heading(mut self, heading: Heading) -> Robot {
+        self.heading = heading;
+        self
+    }
+
+    pub fn hull(&self) -> &Hull {
+        &self.hull
+    }
+
+    pub fn position(&self) -> (i64, i64) {
+        self.position
+    }
+
+    pub fn facing(&self) -> Heading {
+        self.heading
+    }
+
+    // every paint job, oldest first
+    pub fn history(&self) -> &[Stroke] {
+        &self.history
+    }
+
+    // the panel just painted, if the robot hasn't moved off it yet
+    pub fn fresh(&self) -> Option<(i64, i64)> {
+        if self.painting {
+            None
+        } else {
+            Some(self.position)
+        }
+    }
+
+    pub fn paint_count(&self, x: i64, y: i64) -> usize {
+        self.paint_counts.get(&(x, y)).cloned().unwrap_or(0)
+    }
+
+    pub fn paint_counts(&self) -> &HashMap<(i64, i64), usize> {
+        &self.paint_counts
+    }
+
+    pub fn stats(&self) -> Stats {
+        Stats {
+            panels_painted: self.paint_counts.len(),
+            repaints: self.history.len() - self.paint_counts.len(),
+            path_length: self.path_length,
+        }
+    }
+}
+
+impl Default for Robot {
+    fn default() -> Robot {
+        Robot::new()
+    }
+}
+
+impl Device for Robot {
+    fn read(&mut self) -> i64 {
+        match self.hull.get(self.position.0, self.position.1) {
+            Paint::White => 1,
+            _ => 0,
+        }
+    }
+
+    fn write(&mut self, val: i64) {
+        if self.painting {
+            let paint = if val == 1 { Paint::White } else { Paint::Black };
+            let (x, y) = self.position;
+            self.hull.paint(x, y, paint);
+            self.history.push(Stroke { position: (x, y), paint });
+            *self.paint_counts.entry((x, y)).or_insert(0) += 1;
+        } else {
+            self.heading = if val == 0 { self.heading.left() } else { self.heading.right() };
+            self.position = self.heading.step(self.position);
+            self.path_length += 1;
+        }
+        self.painting = !self.painting;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::IntCode;
+
+    #[test]
+    fn test_example() {
+        let mut robot = Robot::new();
+        for &val in &[1, 0, 0, 0, 1, 0, 1, 0, 0, 1, 1, 0, 1, 0] {
+            robot.write(val);
+        }
+        assert_eq!(robot.position(), (0, 1));
+        assert_eq!(robot.facing(), Heading::Left);
+        assert_eq!(robot.read(), 0);
+        assert_eq!(
+            robot.stats(),
+            Stats { panels_painted: 6, repaints: 1, path_length: 7 }
+        );
+        assert_eq!(robot.paint_count(0, 0), 2);
+        assert_eq!(robot.history()[0], Stroke { position: (0, 0), paint: Paint::White });
+        assert_eq!(robot.hull().render(), "  #\n  #\n## \n");
+    }
+
+    #[test]
+    fn test_start_color_and_heading() {
+        // paints the opposite of what it sees, turns right and stops
+        let program = vec![3, 20, 1002, 20, -1, 20, 1001, 20, 1, 20, 4, 20, 104, 1, 99];
+        let mut robot = Robot::new().start_color(Paint::White).heading(Heading::Right);
+        let mut intcode = IntCode::new(&program);
+        intcode.biggen(100);
+        intcode.run_device(&mut robot);
+        assert_eq!(robot.hull().get(0, 0), Paint::Black);
+        assert_eq!(robot.position(), (0, -1));
+        assert_eq!(robot.stats().panels_painted, 1);
+    }
+}
@@ -0,0 +1,179 @@
+use std::thread;
+
+use num::integer::lcm;
+
+use crate::Moon;
+
+// Every moon's position and velocity along one axis, kept in two flat arrays.
+// Gravity along one axis only depends on positions along that axis, so each
+// axis runs on its own.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Axis {
+    pub positions: Vec<i32>,
+    pub velocities: Vec<i32>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Periods {
+    // steps until each axis is back where it started
+    pub axes: [u64; 3],
+    // steps until the whole system is
+    pub total: u128,
+}
+
+impl Axis {
+    pub fn new(moons: &[Moon], dim: usize) -> Axis {
+        Axis {
+            positions: moons.iter().map(|moon| moon.position[dim]).collect(),
+            velocities: moons.iter().map(|moon| moon.velocity[dim]).collect(),
+        }
+    }
+
+    pub fn step(&mut self) {
+        for (i, velocity) in self.velocities.iter_mut().enumerate() {
+            let position = self.positions[i];
+            for &other in &self.positions {
+                *velocity += (other - position).signum();
+            }
+        }
+        for (position, velocity) in self.positions.iter_mut().zip(&self.velocities) {
+            *position += velocity;
+        }
+    }
+
+    // Undoes `step`: the velocities that moved the moons here are the ones
+    // they have now, and the pull that changed them came from where the moons
+    // were before.
+    pub fn step_back(&mut self) {
+        for (position, velocity) in self.positions.iter_mut().zip(&self.velocities) {
+            *position -= velocity;
+        }
+        for (i, velocity) in self.velocities.iter_mut().enumerate() {
+            let position = self.positions[i];
+            for &other in &self.positions {
+                *velocity -= (other - position).signum();
+            }
+        }
+    }
+
+    // forwards for positive steps, backwards for negative ones
+    pub fn run(&mut self, steps: i64) {
+        for _ in 0..steps.abs() {
+            if steps > 0 {
+                self.step();
+            } else {
+                self.step_back();
+            }
+        }
+    }
+
+    // Steps until the axis repeats. Every step can be undone, so the first
+    // state to come round again is always the one it started in.
+    pub fn period(&self) -> u64 {
+        let mut axis = self.clone();
+        let mut steps = 0;
+        loop {
+            axis.step();
+            steps += 1;
+            if axis == *self {
+                return steps;
+            }
+        }
+    }
+}
+
+// u64 periods can have an lcm well beyond u64
+fn total(axes: &[u64; 3]) -> u128 {
+    axes.iter().fold(1, |total, &period| lcm(total, period as u128))
+}
+
+// each axis on a thread of its own
+pub fn periods(moons: &[Moon]) -> Periods {
+    let axes: Vec<Axis> = (0..3).map(|dim| Axis::new(moons, dim)).collect();
+    let mut found = [0; 3];
+    thread::scope(|scope| {
+        let workers: Vec<_> = axes.iter().map(|axis| scope.spawn(move || axis.period())).collect();
+        for (period, worker) in found.iter_mut().zip(workers) {
+            *period = worker.join().unwrap();
+        }
+    });
+    Periods {
+        axes: found,
+        total: total(&found),
+    }
+}
+
+// The moons `steps` steps on from now, or back if it's negative. Each axis
+// only needs the steps left over after whole periods, taken whichever way
+// round is shorter, so this is never more than half a period per axis.
+pub fn jump(moons: &[Moon], periods: &Periods, steps: i128) -> Vec<Moon> {
+    let mut jumped: Vec<Moon> = moons.to_vec();
+    for (dim, &period) in periods.axes.iter().enumerate() {
+        let period = period as i128;
+        let mut reduced = steps.rem_euclid(period);
+        if reduced > period / 2 {
+            reduced -= period;
+        }
+        let mut axis = Axis::new(moons, dim);
+        axis.run(reduced as i64);
+        for (i, moon) in jumped.iter_mut().enumerate() {
+            moon.position[dim] = axis.positions[i];
+            moon.velocity[dim] = axis.velocities[i];
+        }
+    }
+    jumped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse_moons, simulate};
+
+    const EXAMPLE: &str = "<x=-1, y=0, z=2>\n<x=2, y=-10, z=-7>\n<x=4, y=-8, z=8>\n<x=3, y=5, z=-1>";
+
+    #[test]
+    fn test_axis_step() {
+        let moons = parse_moons(EXAMPLE).unwrap();
+        let mut x = Axis::new(&moons, 0);
+        x.step();
+        assert_eq!(x.positions, vec![2, 3, 1, 2]);
+        assert_eq!(x.velocities, vec![3, 1, -3, -1]);
+    }
+
+    #[test]
+    fn test_total_beyond_u64() {
+        let axes = [u64::MAX, u64::MAX - 1, 1];
+        assert_eq!(total(&axes), u64::MAX as u128 * (u64::MAX - 1) as u128);
+        assert_eq!(total(&[18, 28, 44]), 2772);
+    }
+
+    #[test]
+    fn test_step_back() {
+        let moons = parse_moons(EXAMPLE).unwrap();
+        let start = Axis::new(&moons, 1);
+        let mut axis = start.clone();
+        axis.run(37);
+        axis.run(-37);
+        assert_eq!(axis, start);
+        axis.step_back();
+        axis.step();
+        assert_eq!(axis, start);
+    }
+
+    #[test]
+    fn test_jump() {
+        let moons = parse_moons(EXAMPLE).unwrap();
+        let periods = periods(&moons);
+        let mut ten = parse_moons(EXAMPLE).unwrap();
+        simulate(&mut ten, 10);
+        assert_eq!(jump(&moons, &periods, 10), ten);
+        assert_eq!(jump(&moons, &periods, 10 + 5 * 2772), ten);
+        assert_eq!(jump(&moons, &periods, 0), moons);
+        assert_eq!(jump(&ten, &periods, -10), moons);
+        assert_eq!(jump(&moons, &periods, 2772 - 10), jump(&moons, &periods, -10));
+        assert_eq!(
+            jump(&moons, &periods, 1_000_000_000_000),
+            jump(&moons, &periods, 1_000_000_000_000 % 2772)
+        );
+    }
+}
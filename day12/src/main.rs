@@ -1,6 +1,11 @@
 extern crate num;
 
-use num::integer::lcm;
+use std::env;
+use std::fmt;
+use std::fs;
+use std::process;
+
+mod axis;
 
 type Vector = [i32; 3];
 
@@ -12,12 +17,21 @@ fn abs(v: Vector) -> i32 {
     v[0].abs() + v[1].abs() + v[2].abs()
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq)]
 struct Moon {
     position: Vector,
     velocity: Vector,
 }
 
+// the way the puzzle prints them
+impl fmt::Display for Moon {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let [x, y, z] = self.position;
+        let [vx, vy, vz] = self.velocity;
+        write!(f, "pos=<x={}, y={}, z={}>, vel=<x={}, y={}, z={}>", x, y, z, vx, vy, vz)
+    }
+}
+
 fn compare(a: i32, b: i32) -> i32 {
     if a > b {
         -1
@@ -36,6 +50,31 @@ impl Moon {
         }
     }
 
+    // one moon in the puzzle's format, `<x=-1, y=0, z=2>`
+    fn parse(line: &str) -> Result<Moon, String> {
+        let inner = line
+            .trim()
+            .strip_prefix('<')
+            .and_then(|rest| rest.strip_suffix('>'))
+            .ok_or_else(|| format!("expected <x=.., y=.., z=..>, got {:?}", line))?;
+        let fields: Vec<&str> = inner.split(',').map(str::trim).collect();
+        if fields.len() != 3 {
+            return Err(format!("expected 3 coordinates, got {}", fields.len()));
+        }
+        let mut position = [0; 3];
+        for (axis, (name, field)) in ["x", "y", "z"].iter().zip(fields).enumerate() {
+            let value = field
+                .strip_prefix(name)
+                .and_then(|rest| rest.trim_start().strip_prefix('='))
+                .ok_or_else(|| format!("expected {}=.., got {:?}", name, field))?;
+            position[axis] = value
+                .trim()
+                .parse()
+                .map_err(|_| format!("{} is not a number: {:?}", name, value.trim()))?;
+        }
+        Ok(Moon::new(position[0], position[1], position[2]))
+    }
+
     fn apply_velocity(&mut self) {
         self.position = add(self.position, self.velocity);
     }
@@ -51,26 +90,45 @@ impl Moon {
     }
 }
 
+// a moon per line, blank lines skipped
+fn parse_moons(input: &str) -> Result<Vec<Moon>, String> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(i, line)| Moon::parse(line).map_err(|err| format!("line {}: {}", i + 1, err)))
+        .collect()
+}
+
+// usage: day12 [FILE] [--step N]
+// a negative N runs the moons backwards from where they start
 fn main() {
-    let mut moons = vec![
-        Moon::new(-8, -18, 6),
-        Moon::new(-11, -14, 4),
-        Moon::new(8, -3, -10),
-        Moon::new(-2, -16, 1),
-        // Moon::new(-1, 0, 2),
-        // Moon::new(2, -10, -7),
-        // Moon::new(4, -8, 8),
-        // Moon::new(3, 5, -1),
-    ];
+    let args: Vec<String> = env::args().collect();
+    let option = |name: &str| {
+        let i = args.iter().position(|arg| arg == name)?;
+        args.get(i + 1).cloned()
+    };
+    let path = match args.get(1) {
+        Some(path) if !path.starts_with("--") => path.clone(),
+        _ => String::from("data.txt"),
+    };
+    let moons = fs::read_to_string(&path)
+        .map_err(|err| err.to_string())
+        .and_then(|input| parse_moons(&input));
+    let mut moons = match moons {
+        Ok(moons) => moons,
+        Err(err) => {
+            eprintln!("{}: {}", path, err);
+            process::exit(1);
+        }
+    };
+
+    let start = moons.clone();
 
     // part 1
 
     simulate(&mut moons, 1000);
 
-    // for i in 0..4 {
-    //     println!("{:?}: {:?}", moons[i].position, moons[i].velocity);
-    // }
-
     let mut sum = 0;
     for m in &moons {
         sum += m.energy();
@@ -81,63 +139,89 @@ fn main() {
     // part 2
     // credit to https://github.com/frerich/aoc2019/blob/master/rust/day12/src/main.rs
 
-    simulate_until_repeat(&mut moons);
+    let periods = axis::periods(&moons);
+    println!("x {} y {} z {}", periods.axes[0], periods.axes[1], periods.axes[2]);
+    println!("{}", periods.total);
+
+    if let Some(step) = option("--step") {
+        let step: i128 = step.parse().unwrap();
+        let jumped = axis::jump(&start, &periods, step);
+        println!("After {} steps:", step);
+        for moon in &jumped {
+            println!("{}", moon);
+        }
+        println!("energy {}", jumped.iter().map(Moon::energy).sum::<i32>());
+    }
 }
 
-fn simulate(moons: &mut Vec<Moon>, num_steps: i32) {
-    for _ in 0..num_steps {
-        for i in 0..moons.len() {
-            for j in 0..moons.len() {
-                if i != j {
-                    let other = moons[j].position;
-                    moons[i].apply_gravity(other);
-                }
+fn step(moons: &mut [Moon]) {
+    for i in 0..moons.len() {
+        for j in 0..moons.len() {
+            if i != j {
+                let other = moons[j].position;
+                moons[i].apply_gravity(other);
             }
         }
-        for i in 0..moons.len() {
-            moons[i].apply_velocity();
-        }
+    }
+    for moon in moons.iter_mut() {
+        moon.apply_velocity();
     }
 }
 
-fn dimension_state(moons: &[Moon], dim: usize) -> [i32; 8] {
-    [
-        moons[0].position[dim], moons[0].velocity[dim],
-        moons[1].position[dim], moons[1].velocity[dim],
-        moons[2].position[dim], moons[2].velocity[dim],
-        moons[3].position[dim], moons[3].velocity[dim]
-    ]
+fn simulate(moons: &mut [Moon], num_steps: i32) {
+    for _ in 0..num_steps {
+        step(moons);
+    }
 }
 
-fn simulate_until_repeat(moons: &mut Vec<Moon>) {
-    let initial_state = [
-        dimension_state(&moons, 0),
-        dimension_state(&moons, 1),
-        dimension_state(&moons, 2)
-    ];
-
-    let mut cycle_len = [0, 0, 0];
-
-    let mut counter: usize = 1;
-    while cycle_len[0] == 0 || cycle_len[1] == 0 || cycle_len[2] == 0 {
-        for i in 0..moons.len() {
-            for j in 0..moons.len() {
-                if i != j {
-                    let other = moons[j].position;
-                    moons[i].apply_gravity(other);
-                }
-            }
-        }
-        for i in 0..moons.len() {
-            moons[i].apply_velocity();
-        }
-        for i in 0..3 {
-            if dimension_state(&moons, i) == initial_state[i] {
-                cycle_len[i] = counter;
-            }
-        }
-        counter += 1;
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "<x=-1, y=0, z=2>\n<x=2, y=-10, z=-7>\n<x=4, y=-8, z=8>\n<x=3, y=5, z=-1>\n";
+
+    fn energy(moons: &[Moon]) -> i32 {
+        moons.iter().map(Moon::energy).sum()
+    }
+
+    #[test]
+    fn test_parse() {
+        let moons = parse_moons(EXAMPLE).unwrap();
+        assert_eq!(moons.len(), 4);
+        assert_eq!(moons[1].position, [2, -10, -7]);
+        assert_eq!(Moon::parse("< x = 5 ,y=-3,z= 0 >").unwrap().position, [5, -3, 0]);
+
+        let error = |input: &str| parse_moons(input).err().unwrap();
+        assert_eq!(
+            error("<x=1, y=2, z=3>\n\nx=1, y=2, z=3"),
+            "line 3: expected <x=.., y=.., z=..>, got \"x=1, y=2, z=3\""
+        );
+        assert_eq!(error("<x=1, y=2>"), "line 1: expected 3 coordinates, got 2");
+        assert_eq!(error("<x=1, z=2, y=3>"), "line 1: expected y=.., got \"z=2\"");
+        assert_eq!(error("<x=1, y=two, z=3>"), "line 1: y is not a number: \"two\"");
+    }
+
+    #[test]
+    fn test_example() {
+        let mut moons = parse_moons(EXAMPLE).unwrap();
+        simulate(&mut moons, 10);
+        assert_eq!(energy(&moons), 179);
+        let periods = axis::periods(&parse_moons(EXAMPLE).unwrap());
+        assert_eq!(periods.axes, [18, 28, 44]);
+        assert_eq!(periods.total, 2772);
     }
 
-    println!("{:?}", lcm(cycle_len[0], lcm(cycle_len[1], cycle_len[2])));
+    #[test]
+    fn test_any_number_of_moons() {
+        let mut moons = parse_moons("<x=0, y=0, z=0>\n<x=2, y=0, z=0>\n").unwrap();
+        simulate(&mut moons, 1);
+        assert_eq!(moons[0].position, [1, 0, 0]);
+        assert_eq!(moons[1].position, [1, 0, 0]);
+
+        // the example with a fifth moon at the origin
+        let five = format!("{}<x=0, y=0, z=0>\n", EXAMPLE);
+        let periods = axis::periods(&parse_moons(&five).unwrap());
+        assert_eq!(periods.axes, [16, 24, 40]);
+        assert_eq!(periods.total, 240);
+    }
 }
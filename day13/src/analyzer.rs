@@ -0,0 +1,303 @@
+use crate::arcade::{Arcade, Screen, Tile};
+use crate::controller::JoystickController;
+
+// Things the screen shows that the ROM must keep somewhere in memory.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Quantity {
+    BallX,
+    BallY,
+    PaddleX,
+    Score,
+    Blocks,
+}
+
+pub const QUANTITIES: [Quantity; 5] = [
+    Quantity::BallX,
+    Quantity::BallY,
+    Quantity::PaddleX,
+    Quantity::Score,
+    Quantity::Blocks,
+];
+
+impl Quantity {
+    fn observe(self, arcade: &Arcade) -> Option<i64> {
+        let screen = arcade.screen();
+        match self {
+            Quantity::BallX => screen.find(Tile::Ball).map(|(x, _)| x),
+            Quantity::BallY => screen.find(Tile::Ball).map(|(_, y)| y),
+            Quantity::PaddleX => screen.find(Tile::Paddle).map(|(x, _)| x),
+            Quantity::Score => Some(arcade.score()),
+            Quantity::Blocks => Some(screen.count(Tile::Block) as i64),
+        }
+    }
+}
+
+struct Watch {
+    quantity: Quantity,
+    candidates: Option<Vec<usize>>,
+    values: Vec<i64>,
+}
+
+// The ROM's copy of the board, one cell per tile, row by row.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TileMap {
+    pub base: usize,
+    pub width: usize,
+    pub height: usize,
+}
+
+impl TileMap {
+    pub fn address(&self, x: i64, y: i64) -> usize {
+        self.base + y as usize * self.width + x as usize
+    }
+}
+
+// Points for each block, looked up by hashing its position:
+// table[((x * height + y) * multiplier + offset) % len], or with y * width + x
+// as the key when `by_rows` is set.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ScoreTable {
+    pub base: usize,
+    pub len: usize,
+    pub multiplier: usize,
+    pub offset: usize,
+    pub by_rows: bool,
+    width: usize,
+    height: usize,
+}
+
+impl ScoreTable {
+    pub fn address(&self, x: i64, y: i64) -> usize {
+        let key = if self.by_rows {
+            y as usize * self.width + x as usize
+        } else {
+            x as usize * self.height + y as usize
+        };
+        self.base + (key * self.multiplier + self.offset) % self.len
+    }
+
+    // what the game will have scored once every block left is gone
+    pub fn final_score(&self, arcade: &Arcade) -> i64 {
+        let blocks = arcade
+            .screen()
+            .cells()
+            .filter(|&(_, _, tile)| tile == Tile::Block);
+        arcade.score() + blocks.map(|(x, y, _)| arcade.memory()[self.address(x, y)]).sum::<i64>()
+    }
+}
+
+// Watches the ROM's memory while a game runs, like a cheat finder: every
+// quantity starts out matching every cell holding the same value, and each
+// tick drops the cells that no longer agree with the screen.
+pub struct Analyzer {
+    watches: Vec<Watch>,
+    breaks: Vec<(i64, i64, i64)>,
+    last: Option<(Screen, i64)>,
+}
+
+impl Analyzer {
+    pub fn new() -> Analyzer {
+        Analyzer {
+            watches: QUANTITIES
+                .iter()
+                .map(|&quantity| Watch {
+                    quantity,
+                    candidates: None,
+                    values: Vec::new(),
+                })
+                .collect(),
+            breaks: Vec::new(),
+            last: None,
+        }
+    }
+
+    pub fn observe(&mut self, arcade: &Arcade) {
+        let memory = arcade.memory();
+        for watch in &mut self.watches {
+            if let Some(value) = watch.quantity.observe(arcade) {
+                watch.candidates = Some(match watch.candidates.take() {
+                    None => (0..memory.len()).filter(|&a| memory[a] == value).collect(),
+                    Some(candidates) => candidates
+                        .into_iter()
+                        .filter(|&a| memory[a] == value)
+                        .collect(),
+                });
+                if !watch.values.contains(&value) {
+                    watch.values.push(value);
+                }
+            }
+        }
+
+        // remember which block scored what, when only one broke at a time
+        if let Some((screen, score)) = &self.last {
+            let broken: Vec<(i64, i64)> = screen
+                .cells()
+                .filter(|&(x, y, tile)| tile == Tile::Block && arcade.screen().get(x, y) != Tile::Block)
+                .map(|(x, y, _)| (x, y))
+                .collect();
+            if let [(x, y)] = broken[..] {
+                if arcade.score() > *score {
+                    self.breaks.push((x, y, arcade.score() - score));
+                }
+            }
+        }
+        self.last = Some((arcade.screen().clone(), arcade.score()));
+    }
+
+    // plays `ticks` ticks with `controller`, observing every one
+    pub fn watch(&mut self, arcade: &mut Arcade, controller: &mut dyn JoystickController, ticks: usize) {
+        self.observe(arcade);
+        for _ in 0..ticks {
+            if !arcade.is_running() {
+                break;
+            }
+            let joystick = controller.joystick(arcade, None);
+            arcade.tick(joystick);
+            self.observe(arcade);
+        }
+    }
+
+    // every cell that has agreed with the quantity so far
+    pub fn candidates(&self, quantity: Quantity) -> &[usize] {
+        self.watches
+            .iter()
+            .find(|watch| watch.quantity == quantity)
+            .and_then(|watch| watch.candidates.as_deref())
+            .unwrap_or(&[])
+    }
+
+    // The cell holding the quantity, once it has been seen to change (so a
+    // cell that just happens to hold the same constant doesn't count) and
+    // only one cell is left.
+    pub fn locate(&self, quantity: Quantity) -> Option<usize> {
+        let watch = self.watches.iter().find(|watch| watch.quantity == quantity)?;
+        match watch.candidates.as_deref() {
+            Some([address]) if watch.values.len() > 1 => Some(*address),
+            _ => None,
+        }
+    }
+
+    // where the ROM keeps the board, if it starts at (0, 0) like the screen
+    pub fn locate_tiles(&self, arcade: &Arcade) -> Option<TileMap> {
+        let screen = arcade.screen();
+        let memory = arcade.memory();
+        if screen.origin() != (0, 0) {
+            return None;
+        }
+        let (width, height) = (screen.width(), screen.height());
+        (0..memory.len().saturating_sub(width * height))
+            .map(|base| TileMap { base, width, height })
+            .find(|map| {
+                screen
+                    .cells()
+                    .all(|(x, y, tile)| memory[map.address(x, y)] == tile.id())
+            })
+    }
+
+    // Finds the table of points per block, assuming it comes straight after
+    // the tile map and is the same size, by fitting the hash to the blocks
+    // seen breaking so far. It takes a handful of them to be sure.
+    pub fn locate_score_table(&self, arcade: &Arcade, map: &TileMap) -> Option<ScoreTable> {
+        if self.breaks.len() < 5 {
+            return None;
+        }
+        let memory = arcade.memory();
+        let len = map.width * map.height;
+        let base = map.base + len;
+        let table = memory.get(base..base + len)?;
+        let (x0, y0, points0) = self.breaks[0];
+
+        for &by_rows in &[false, true] {
+            for multiplier in 1..len {
+                let mut fit = ScoreTable {
+                    base,
+                    len,
+                    multiplier,
+                    offset: 0,
+                    by_rows,
+                    width: map.width,
+                    height: map.height,
+                };
+                // the first break pins the offset down to a few choices
+                let key0 = fit.address(x0, y0) - base;
+                for index in (0..len).filter(|&i| table[i] == points0) {
+                    fit.offset = (index + len - key0) % len;
+                    let fits = self
+                        .breaks
+                        .iter()
+                        .all(|&(x, y, points)| memory[fit.address(x, y)] == points);
+                    if fits {
+                        return Some(fit);
+                    }
+                }
+            }
+        }
+        None
+    }
+}
+
+impl Default for Analyzer {
+    fn default() -> Analyzer {
+        Analyzer::new()
+    }
+}
+
+// Fills the paddle's row with paddle, wall to wall, so the ball can't get
+// past. The ROM blanks the cells a moving paddle leaves behind, so this only
+// holds while the joystick stays neutral.
+pub fn widen_paddle(arcade: &mut Arcade, map: &TileMap) {
+    if let Some((_, y)) = arcade.screen().find(Tile::Paddle) {
+        fill_row(arcade, map, y, Tile::Paddle);
+    }
+}
+
+// Walls off the bottom row, so a missed ball bounces back instead of ending
+// the game.
+pub fn infinite_lives(arcade: &mut Arcade, map: &TileMap) {
+    fill_row(arcade, map, map.height as i64 - 1, Tile::Wall);
+}
+
+fn fill_row(arcade: &mut Arcade, map: &TileMap, y: i64, tile: Tile) {
+    for x in 0..map.width as i64 {
+        if arcade.screen().get(x, y) != Tile::Wall {
+            arcade.poke_tile(map.address(x, y), x, y, tile);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // draws a wall, then each tick moves the ball (x at 50) one right and
+    // adds 5 to the score (at 51)
+    fn rom() -> Vec<i64> {
+        let mut rom = vec![
+            104, 0, 104, 0, 104, 1,
+            4, 50, 104, 1, 104, 4,
+            104, -1, 104, 0, 4, 51,
+            3, 52,
+            4, 50, 104, 1, 104, 0,
+            1001, 50, 1, 50,
+            1001, 51, 5, 51,
+            1105, 1, 6,
+        ];
+        rom.resize(50, 0);
+        rom.extend(&[1, 0]);
+        rom
+    }
+
+    #[test]
+    fn test_locate_cells() {
+        let mut arcade = Arcade::new(&rom());
+        let mut analyzer = Analyzer::new();
+        analyzer.observe(&arcade);
+        assert_eq!(analyzer.locate(Quantity::BallX), None);
+        for _ in 0..3 {
+            arcade.tick(0);
+            analyzer.observe(&arcade);
+        }
+        assert_eq!(analyzer.locate(Quantity::BallX), Some(50));
+        assert_eq!(analyzer.locate(Quantity::Score), Some(51));
+    }
+}
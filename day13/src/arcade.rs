@@ -0,0 +1,385 @@
+use crate::event::{Event, IntoEvents};
+use crate::{IntCode, Status};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Tile {
+    Empty,
+    Wall,
+    Block,
+    Paddle,
+    Ball,
+}
+
+impl Tile {
+    pub fn from_id(id: i64) -> Option<Tile> {
+        match id {
+            0 => Some(Tile::Empty),
+            1 => Some(Tile::Wall),
+            2 => Some(Tile::Block),
+            3 => Some(Tile::Paddle),
+            4 => Some(Tile::Ball),
+            _ => None,
+        }
+    }
+
+    pub fn id(self) -> i64 {
+        match self {
+            Tile::Empty => 0,
+            Tile::Wall => 1,
+            Tile::Block => 2,
+            Tile::Paddle => 3,
+            Tile::Ball => 4,
+        }
+    }
+
+    pub fn symbol(self) -> char {
+        match self {
+            Tile::Empty => ' ',
+            Tile::Wall => '|',
+            Tile::Block => 'X',
+            Tile::Paddle => '=',
+            Tile::Ball => 'O',
+        }
+    }
+}
+
+// How far from (0, 0) the screen reaches in any direction, which keeps it to
+// a few million tiles whatever the ROM prints. The real game needs under 50.
+pub const MAX_COORDINATE: i64 = 1024;
+
+// Everything the arcade has drawn. It grows to fit whatever the ROM draws,
+// negative coordinates included, and counts the tiles as it goes.
+#[derive(Clone)]
+pub struct Screen {
+    rows: Vec<Vec<Tile>>,
+    left: i64,
+    top: i64,
+    counts: [usize; 5],
+}
+
+impl Screen {
+    pub fn new() -> Screen {
+        Screen {
+            rows: Vec::new(),
+            left: 0,
+            top: 0,
+            counts: [0; 5],
+        }
+    }
+
+    // rows from the top, each `width` tiles from the left edge
+    pub fn rows(&self) -> &[Vec<Tile>] {
+        &self.rows
+    }
+
+    pub fn width(&self) -> usize {
+        self.rows.first().map_or(0, |row| row.len())
+    }
+
+    pub fn height(&self) -> usize {
+        self.rows.len()
+    }
+
+    // the coordinates of the top left tile
+    pub fn origin(&self) -> (i64, i64) {
+        (self.left, self.top)
+    }
+
+    pub fn count(&self, tile: Tile) -> usize {
+        self.counts[tile.id() as usize]
+    }
+
+    // every tile with its coordinates, row by row
+    pub fn cells(&self) -> impl Iterator<Item = (i64, i64, Tile)> + '_ {
+        self.rows.iter().enumerate().flat_map(move |(y, row)| {
+            row.iter()
+                .enumerate()
+                .map(move |(x, tile)| (self.left + x as i64, self.top + y as i64, *tile))
+        })
+    }
+
+    // anything outside what's been drawn is empty
+    pub fn get(&self, x: i64, y: i64) -> Tile {
+        let (col, row) = (x - self.left, y - self.top);
+        if col < 0 || row < 0 {
+            return Tile::Empty;
+        }
+        self.rows
+            .get(row as usize)
+            .and_then(|row| row.get(col as usize))
+            .cloned()
+            .unwrap_or(Tile::Empty)
+    }
+
+    // whether (x, y) is close enough to (0, 0) to draw
+    pub fn holds(x: i64, y: i64) -> bool {
+        let range = -MAX_COORDINATE..=MAX_COORDINATE;
+        range.contains(&x) && range.contains(&y)
+    }
+
+    // Tiles beyond MAX_COORDINATE are left undrawn; the arcade reports them
+    // as bad output before they get here.
+    pub fn set(&mut self, x: i64, y: i64, tile: Tile) {
+        if !Screen::holds(x, y) || self.grow(x, y).is_none() {
+            return;
+        }
+        let cell = &mut self.rows[(y - self.top) as usize][(x - self.left) as usize];
+        self.counts[cell.id() as usize] -= 1;
+        self.counts[tile.id() as usize] += 1;
+        *cell = tile;
+    }
+
+    // makes room for (x, y), filling the new space with empty tiles; the
+    // screen always reaches (0, 0) so a board drawn from there lines up.
+    // None if the new size doesn't add up, which held coordinates never do.
+    fn grow(&mut self, x: i64, y: i64) -> Option<()> {
+        if self.rows.is_empty() {
+            self.rows = vec![vec![Tile::Empty]];
+            self.counts[Tile::Empty.id() as usize] += 1;
+        }
+        let (width, height) = (self.width() as i64, self.height() as i64);
+        let (right, bottom) = (self.left.checked_add(width)?, self.top.checked_add(height)?);
+        if (self.left..right).contains(&x) && (self.top..bottom).contains(&y) {
+            return Some(());
+        }
+
+        let (left, top) = (self.left.min(x), self.top.min(y));
+        let new_width = right.max(x.checked_add(1)?).checked_sub(left)? as usize;
+        let new_height = bottom.max(y.checked_add(1)?).checked_sub(top)? as usize;
+        let mut rows = vec![vec![Tile::Empty; new_width]; new_height];
+        let (dx, dy) = ((self.left - left) as usize, (self.top - top) as usize);
+        for (y, row) in self.rows.iter().enumerate() {
+            rows[y + dy][dx..dx + row.len()].copy_from_slice(row);
+        }
+        self.counts[Tile::Empty.id() as usize] += new_width * new_height - (width * height) as usize;
+        self.rows = rows;
+        self.left = left;
+        self.top = top;
+        Some(())
+    }
+
+    pub fn find(&self, tile: Tile) -> Option<(i64, i64)> {
+        for (y, row) in self.rows.iter().enumerate() {
+            if let Some(x) = row.iter().position(|t| *t == tile) {
+                return Some((self.left + x as i64, self.top + y as i64));
+            }
+        }
+        None
+    }
+}
+
+impl Default for Screen {
+    fn default() -> Screen {
+        Screen::new()
+    }
+}
+
+// The game asks for the joystick once per tick, so the arcade runs the ROM
+// until that read and keeps the screen and score it has drawn so far. Output
+// that isn't a tile or a score stops the game with an error instead.
+#[derive(Clone)]
+pub struct Arcade {
+    program: IntCode,
+    screen: Screen,
+    score: i64,
+    output: Vec<i64>,
+    updates: Vec<Event>,
+    status: Status,
+    error: Option<String>,
+}
+
+impl Arcade {
+    pub fn new(rom: &[i64]) -> Arcade {
+        let mut program = IntCode::new(rom);
+        program.biggen(10_000);
+        let mut arcade = Arcade {
+            program,
+            screen: Screen::new(),
+            score: 0,
+            output: Vec::new(),
+            updates: Vec::new(),
+            status: Status::Waiting,
+            error: None,
+        };
+        arcade.advance(None);
+        arcade
+    }
+
+    pub fn screen(&self) -> &Screen {
+        &self.screen
+    }
+
+    pub fn score(&self) -> i64 {
+        self.score
+    }
+
+    pub fn memory(&self) -> &[i64] {
+        self.program.memory()
+    }
+
+    // changes the ROM's memory behind its back
+    pub fn poke(&mut self, address: usize, value: i64) {
+        self.program.poke(address, value);
+    }
+
+    // the same for a cell of the ROM's own copy of the board, keeping the
+    // screen in step with it
+    pub fn poke_tile(&mut self, address: usize, x: i64, y: i64, tile: Tile) {
+        self.poke(address, tile.id());
+        self.screen.set(x, y, tile);
+    }
+
+    // what was drawn during the last tick
+    pub fn updates(&self) -> &[Event] {
+        &self.updates
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.status == Status::Waiting && self.error.is_none()
+    }
+
+    // why the game stopped, if the ROM printed something it shouldn't have
+    pub fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    pub fn tick(&mut self, joystick: i64) {
+        if self.is_running() {
+            self.advance(Some(joystick));
+        }
+    }
+
+    fn advance(&mut self, joystick: Option<i64>) {
+        let output = &mut self.output;
+        self.status = self.program.resume(joystick, |val| output.push(val));
+
+        // everything drawn before a bad triple still counts; a triple can be
+        // finished next tick, but once the ROM halts whatever's left is all
+        // there will be
+        let complete = if self.status == Status::Halted {
+            self.output.len()
+        } else {
+            self.output.len() - self.output.len() % 3
+        };
+        self.updates.clear();
+        for event in self.output.drain(..complete).events() {
+            match event {
+                Ok(event) => self.updates.push(event),
+                Err(e) => {
+                    self.error = Some(format!("bad arcade output: {}", e));
+                    break;
+                }
+            }
+        }
+        for event in &self.updates {
+            match *event {
+                Event::TileUpdate { x, y, tile } => self.screen.set(x, y, tile),
+                Event::ScoreUpdate(score) => self.score = score,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_draw_until_input() {
+        // draw a wall, a ball and a score, then read the joystick and move
+        // the ball to the right as far as it says
+        let rom = vec![
+            104, 0, 104, 0, 104, 1,
+            104, 1, 104, 1, 104, 4,
+            104, -1, 104, 0, 104, 7,
+            3, 100,
+            104, 1, 104, 1, 104, 0,
+            101, 1, 100, 100,
+            4, 100, 104, 1, 104, 4,
+            99,
+        ];
+        let mut arcade = Arcade::new(&rom);
+        assert!(arcade.is_running());
+        assert_eq!(arcade.score(), 7);
+        assert_eq!(arcade.screen().get(0, 0), Tile::Wall);
+        assert_eq!(arcade.screen().find(Tile::Ball), Some((1, 1)));
+
+        arcade.tick(1);
+        assert!(!arcade.is_running());
+        assert_eq!(arcade.screen().find(Tile::Ball), Some((2, 1)));
+        assert_eq!(arcade.screen().get(1, 1), Tile::Empty);
+    }
+
+    #[test]
+    fn test_bad_tile() {
+        // a wall, then tile 7, which isn't one
+        let rom = vec![104, 0, 104, 0, 104, 1, 104, 1, 104, 0, 104, 7, 3, 100, 99];
+        let mut arcade = Arcade::new(&rom);
+        assert!(!arcade.is_running());
+        assert_eq!(arcade.error(), Some("bad arcade output: unknown tile id 7 at (1, 0)"));
+        assert_eq!(arcade.screen().get(0, 0), Tile::Wall);
+        // the game is over, so the ROM doesn't run again
+        arcade.tick(0);
+        assert_eq!(arcade.updates(), &[Event::TileUpdate { x: 0, y: 0, tile: Tile::Wall }]);
+    }
+
+    #[test]
+    fn test_halt_partway_through_triple() {
+        // a wall, then a lone x before halting
+        let rom = vec![104, 0, 104, 0, 104, 1, 104, 5, 99];
+        let arcade = Arcade::new(&rom);
+        assert!(!arcade.is_running());
+        assert_eq!(arcade.error(), Some("bad arcade output: output ends partway through a triple: [5]"));
+        assert_eq!(arcade.screen().get(0, 0), Tile::Wall);
+
+        // but a triple split across ticks is fine
+        let rom = vec![104, 0, 3, 100, 104, 0, 104, 1, 99];
+        let mut arcade = Arcade::new(&rom);
+        assert!(arcade.is_running());
+        arcade.tick(0);
+        assert_eq!(arcade.error(), None);
+        assert_eq!(arcade.screen().get(0, 0), Tile::Wall);
+    }
+
+    #[test]
+    fn test_far_off_tiles() {
+        // a ball at x = i64::MAX, then one at (2^33, 2^33)
+        let rom = vec![104, i64::MAX, 104, 0, 104, 4, 3, 100, 99];
+        let arcade = Arcade::new(&rom);
+        assert!(!arcade.is_running());
+        assert_eq!(
+            arcade.error(),
+            Some(format!("bad arcade output: tile at ({}, 0) is off the screen", i64::MAX).as_str())
+        );
+        let rom = vec![104, 1 << 33, 104, 1 << 33, 104, 4, 3, 100, 99];
+        assert!(Arcade::new(&rom).error().is_some());
+
+        // and the screen itself leaves them alone
+        let mut screen = Screen::new();
+        screen.set(1, 1, Tile::Wall);
+        screen.set(i64::MAX, 0, Tile::Ball);
+        screen.set(i64::MIN, 1 << 33, Tile::Ball);
+        assert_eq!((screen.width(), screen.height()), (2, 2));
+        assert_eq!(screen.count(Tile::Ball), 0);
+        screen.set(-MAX_COORDINATE, MAX_COORDINATE, Tile::Ball);
+        assert_eq!(screen.width() as i64, MAX_COORDINATE + 2);
+    }
+
+    #[test]
+    fn test_screen_grows() {
+        let mut screen = Screen::new();
+        assert_eq!((screen.width(), screen.height()), (0, 0));
+        screen.set(2, 1, Tile::Block);
+        screen.set(-1, 3, Tile::Ball);
+        screen.set(2, 1, Tile::Wall);
+        assert_eq!((screen.width(), screen.height()), (4, 4));
+        assert_eq!(screen.origin(), (-1, 0));
+        assert_eq!(screen.get(2, 1), Tile::Wall);
+        assert_eq!(screen.get(-5, -5), Tile::Empty);
+        assert_eq!(screen.find(Tile::Ball), Some((-1, 3)));
+        assert_eq!(screen.count(Tile::Block), 0);
+        assert_eq!(screen.count(Tile::Wall), 1);
+        assert_eq!(screen.count(Tile::Empty), 14);
+        assert_eq!(screen.cells().count(), 16);
+    }
+}
@@ -0,0 +1,170 @@
+use crate::arcade::{Arcade, Tile};
+use crate::recording::Recording;
+
+// Decides the joystick position (-1 left, 0 neutral, 1 right) for the next
+// tick. `key` is whatever the frontend read from the keyboard since the last
+// tick, if anything.
+pub trait JoystickController {
+    fn joystick(&mut self, arcade: &Arcade, key: Option<char>) -> i64;
+}
+
+fn towards(from: i64, to: i64) -> i64 {
+    (to - from).signum()
+}
+
+// Keeps the paddle under the ball.
+pub struct FollowBall;
+
+impl JoystickController for FollowBall {
+    fn joystick(&mut self, arcade: &Arcade, _key: Option<char>) -> i64 {
+        let screen = arcade.screen();
+        match (screen.find(Tile::Paddle), screen.find(Tile::Ball)) {
+            (Some((padpos, _)), Some((ballpos, _))) => towards(padpos, ballpos),
+            _ => 0,
+        }
+    }
+}
+
+// Plays the game ahead on a copy of the arcade to see where the ball will
+// next come down to the row above the paddle, and waits there. The paddle
+// can't change the ball's path before then, so the copy leaves the joystick
+// alone.
+pub struct Predictive;
+
+impl Predictive {
+    pub fn landing(arcade: &Arcade) -> Option<(i64, i64)> {
+        let (_, py) = arcade.screen().find(Tile::Paddle)?;
+        let mut future = arcade.clone();
+        for _ in 0..10_000 {
+            let ball = future.screen().find(Tile::Ball)?;
+            if ball.1 == py - 1 {
+                return Some(ball);
+            }
+            if !future.is_running() {
+                return None;
+            }
+            future.tick(0);
+        }
+        None
+    }
+}
+
+impl JoystickController for Predictive {
+    fn joystick(&mut self, arcade: &Arcade, _key: Option<char>) -> i64 {
+        match (arcade.screen().find(Tile::Paddle), Predictive::landing(arcade)) {
+            (Some((padpos, _)), Some((target, _))) => towards(padpos, target),
+            _ => 0,
+        }
+    }
+}
+
+// Leaves the joystick alone.
+pub struct Neutral;
+
+impl JoystickController for Neutral {
+    fn joystick(&mut self, _arcade: &Arcade, _key: Option<char>) -> i64 {
+        0
+    }
+}
+
+pub struct Keyboard {
+    left: char,
+    right: char,
+}
+
+impl Keyboard {
+    pub fn new(left: char, right: char) -> Keyboard {
+        Keyboard { left, right }
+    }
+}
+
+impl Default for Keyboard {
+    fn default() -> Keyboard {
+        Keyboard::new('s', 'l')
+    }
+}
+
+impl JoystickController for Keyboard {
+    fn joystick(&mut self, _arcade: &Arcade, key: Option<char>) -> i64 {
+        match key {
+            Some(key) if key == self.left => -1,
+            Some(key) if key == self.right => 1,
+            _ => 0,
+        }
+    }
+}
+
+// Plays back the inputs of a recording, then leaves the joystick neutral.
+pub struct Replay {
+    inputs: Vec<i64>,
+    next: usize,
+}
+
+impl Replay {
+    pub fn new(recording: &Recording) -> Replay {
+        Replay {
+            inputs: recording.joysticks().collect(),
+            next: 0,
+        }
+    }
+}
+
+impl JoystickController for Replay {
+    fn joystick(&mut self, _arcade: &Arcade, _key: Option<char>) -> i64 {
+        let joystick = self.inputs.get(self.next).cloned().unwrap_or(0);
+        self.next += 1;
+        joystick
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // draws the ball and paddle, then waits for the joystick forever
+    fn arcade(ball: (i64, i64), paddle: (i64, i64)) -> Arcade {
+        Arcade::new(&[
+            104, ball.0, 104, ball.1, 104, 4,
+            104, paddle.0, 104, paddle.1, 104, 3,
+            3, 100, 1105, 1, 12,
+        ])
+    }
+
+    #[test]
+    fn test_follow_ball() {
+        assert_eq!(FollowBall.joystick(&arcade((3, 2), (5, 9)), None), -1);
+        assert_eq!(FollowBall.joystick(&arcade((5, 2), (5, 9)), None), 0);
+        assert_eq!(FollowBall.joystick(&arcade((7, 2), (5, 9)), None), 1);
+    }
+
+    #[test]
+    fn test_predictive_waits_for_ball() {
+        // the ball never moves here, so it only lands if it's on the row
+        // above the paddle already
+        assert_eq!(Predictive.joystick(&arcade((3, 8), (5, 9)), None), -1);
+        assert_eq!(Predictive::landing(&arcade((3, 8), (5, 9))), Some((3, 8)));
+        assert_eq!(Predictive::landing(&arcade((3, 2), (5, 9))), None);
+    }
+
+    #[test]
+    fn test_keyboard() {
+        let arcade = arcade((0, 0), (0, 1));
+        let mut keyboard = Keyboard::new('a', 'd');
+        assert_eq!(keyboard.joystick(&arcade, Some('a')), -1);
+        assert_eq!(keyboard.joystick(&arcade, Some('d')), 1);
+        assert_eq!(keyboard.joystick(&arcade, Some('x')), 0);
+        assert_eq!(keyboard.joystick(&arcade, None), 0);
+    }
+
+    #[test]
+    fn test_replay() {
+        let mut recording = Recording::new();
+        recording.record_joystick(1);
+        recording.record_joystick(-1);
+        let arcade = arcade((0, 0), (0, 1));
+        let mut replay = Replay::new(&recording);
+        assert_eq!(replay.joystick(&arcade, None), 1);
+        assert_eq!(replay.joystick(&arcade, None), -1);
+        assert_eq!(replay.joystick(&arcade, None), 0);
+    }
+}
@@ -0,0 +1,88 @@
+use crate::arcade::{Screen, Tile};
+
+// What one (x, y, value) triple of arcade output means.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Event {
+    TileUpdate { x: i64, y: i64, tile: Tile },
+    ScoreUpdate(i64),
+}
+
+impl Event {
+    pub fn decode([x, y, val]: [i64; 3]) -> Result<Event, String> {
+        if x == -1 && y == 0 {
+            return Ok(Event::ScoreUpdate(val));
+        }
+        if !Screen::holds(x, y) {
+            return Err(format!("tile at ({}, {}) is off the screen", x, y));
+        }
+        match Tile::from_id(val) {
+            Some(tile) => Ok(Event::TileUpdate { x, y, tile }),
+            None => Err(format!("unknown tile id {} at ({}, {})", val, x, y)),
+        }
+    }
+
+    // the triple the ROM printed for this event
+    pub fn triple(self) -> [i64; 3] {
+        match self {
+            Event::TileUpdate { x, y, tile } => [x, y, tile.id()],
+            Event::ScoreUpdate(score) => [-1, 0, score],
+        }
+    }
+}
+
+// Groups raw output into triples and decodes them. Output that stops partway
+// through a triple is an error too.
+pub struct Events<I> {
+    output: I,
+}
+
+impl<I: Iterator<Item = i64>> Iterator for Events<I> {
+    type Item = Result<Event, String>;
+
+    fn next(&mut self) -> Option<Result<Event, String>> {
+        let x = self.output.next()?;
+        match (self.output.next(), self.output.next()) {
+            (Some(y), Some(val)) => Some(Event::decode([x, y, val])),
+            (y, _) => {
+                let rest: Vec<i64> = std::iter::once(x).chain(y).collect();
+                Some(Err(format!("output ends partway through a triple: {:?}", rest)))
+            }
+        }
+    }
+}
+
+pub trait IntoEvents: Iterator<Item = i64> + Sized {
+    fn events(self) -> Events<Self> {
+        Events { output: self }
+    }
+}
+
+impl<I: Iterator<Item = i64>> IntoEvents for I {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_events() {
+        let output = vec![1, 2, 3, -1, 0, 42, 0, 0, 1];
+        let events: Vec<_> = output.into_iter().events().collect();
+        assert_eq!(
+            events,
+            vec![
+                Ok(Event::TileUpdate { x: 1, y: 2, tile: Tile::Paddle }),
+                Ok(Event::ScoreUpdate(42)),
+                Ok(Event::TileUpdate { x: 0, y: 0, tile: Tile::Wall }),
+            ]
+        );
+        assert_eq!(events[1].clone().unwrap().triple(), [-1, 0, 42]);
+    }
+
+    #[test]
+    fn test_rejects_bad_output() {
+        let mut events = vec![3, 4, 7, 5].into_iter().events();
+        assert_eq!(events.next(), Some(Err("unknown tile id 7 at (3, 4)".to_string())));
+        assert!(events.next().unwrap().is_err());
+        assert_eq!(events.next(), None);
+    }
+}
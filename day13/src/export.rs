@@ -0,0 +1,190 @@
+use std::fs::{self, File};
+use std::io::{self, BufWriter, Write};
+use std::path::PathBuf;
+
+use crate::arcade::{Screen, Tile};
+use crate::frontend::Frontend;
+
+// An asciinema v2 recording: a JSON header line, then one
+// `[seconds, "o", text]` event per frame that clears the terminal and prints
+// the board.
+pub struct Asciicast<W: Write> {
+    out: W,
+    fps: f64,
+    frame: u64,
+}
+
+impl<W: Write> Asciicast<W> {
+    pub fn new(mut out: W, width: usize, height: usize, fps: f64) -> io::Result<Asciicast<W>> {
+        writeln!(
+            out,
+            "{{\"version\": 2, \"width\": {}, \"height\": {}}}",
+            width, height
+        )?;
+        Ok(Asciicast { out, fps, frame: 0 })
+    }
+
+    pub fn into_inner(self) -> W {
+        self.out
+    }
+
+    fn event(&mut self, text: &str) {
+        let time = self.frame as f64 / self.fps;
+        writeln!(self.out, "[{:.3}, \"o\", \"{}\"]", time, escape(text)).unwrap();
+    }
+}
+
+fn escape(text: &str) -> String {
+    let mut escaped = String::new();
+    for c in text.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            c if (c as u32) < 0x20 => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+impl<W: Write> Frontend for Asciicast<W> {
+    fn draw(&mut self, screen: &Screen, score: i64) {
+        let mut text = format!("\x1b[H\x1b[2Jscore: {}\r\n", score);
+        for row in screen.rows() {
+            let line: String = row.iter().map(|tile| tile.symbol()).collect();
+            text.push_str(line.trim_end());
+            text.push_str("\r\n");
+        }
+        self.event(&text);
+        self.frame += 1;
+    }
+
+    fn message(&mut self, text: &str) {
+        self.event(&format!("{}\r\n", text));
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Palette {
+    pub empty: [u8; 3],
+    pub wall: [u8; 3],
+    pub block: [u8; 3],
+    pub paddle: [u8; 3],
+    pub ball: [u8; 3],
+}
+
+impl Palette {
+    pub fn color(&self, tile: Tile) -> [u8; 3] {
+        match tile {
+            Tile::Empty => self.empty,
+            Tile::Wall => self.wall,
+            Tile::Block => self.block,
+            Tile::Paddle => self.paddle,
+            Tile::Ball => self.ball,
+        }
+    }
+}
+
+impl Default for Palette {
+    fn default() -> Palette {
+        Palette {
+            empty: [0, 0, 0],
+            wall: [128, 128, 128],
+            block: [200, 60, 40],
+            paddle: [60, 120, 220],
+            ball: [255, 255, 255],
+        }
+    }
+}
+
+// Writes every frame as dir/frame_00000.ppm, dir/frame_00001.ppm, ... with
+// each tile drawn as a `scale` by `scale` square.
+pub struct PpmFrames {
+    dir: PathBuf,
+    palette: Palette,
+    scale: usize,
+    frame: usize,
+}
+
+impl PpmFrames {
+    pub fn new(dir: &str, palette: Palette, scale: usize) -> io::Result<PpmFrames> {
+        fs::create_dir_all(dir)?;
+        Ok(PpmFrames {
+            dir: PathBuf::from(dir),
+            palette,
+            scale,
+            frame: 0,
+        })
+    }
+}
+
+pub fn write_ppm<W: Write>(out: &mut W, screen: &Screen, palette: &Palette, scale: usize) -> io::Result<()> {
+    let rows = screen.rows();
+    let width = rows.first().map_or(0, |row| row.len());
+    writeln!(out, "P6\n{} {}\n255", width * scale, rows.len() * scale)?;
+    for row in rows {
+        let mut line = Vec::with_capacity(width * scale * 3);
+        for tile in row {
+            for _ in 0..scale {
+                line.extend_from_slice(&palette.color(*tile));
+            }
+        }
+        for _ in 0..scale {
+            out.write_all(&line)?;
+        }
+    }
+    Ok(())
+}
+
+impl Frontend for PpmFrames {
+    fn draw(&mut self, screen: &Screen, _score: i64) {
+        let path = self.dir.join(format!("frame_{:05}.ppm", self.frame));
+        let mut out = BufWriter::new(File::create(path).unwrap());
+        write_ppm(&mut out, screen, &self.palette, self.scale).unwrap();
+        self.frame += 1;
+    }
+
+    fn message(&mut self, _text: &str) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_asciicast() {
+        let mut screen = Screen::new();
+        screen.set(0, 0, Tile::Wall);
+        let mut cast = Asciicast::new(Vec::new(), 50, 27, 10.0).unwrap();
+        cast.draw(&screen, 0);
+        cast.draw(&screen, 5);
+        let text = String::from_utf8(cast.into_inner()).unwrap();
+        let lines: Vec<&str> = text.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[0], "{\"version\": 2, \"width\": 50, \"height\": 27}");
+        assert!(lines[1].starts_with("[0.000, \"o\", \"\\u001b[H\\u001b[2Jscore: 0\\r\\n|\\r\\n"));
+        assert!(lines[2].starts_with("[0.100, \"o\", \"\\u001b[H\\u001b[2Jscore: 5\\r\\n"));
+    }
+
+    #[test]
+    fn test_ppm() {
+        let mut screen = Screen::new();
+        screen.set(1, 0, Tile::Ball);
+        let palette = Palette::default();
+        let mut out = Vec::new();
+        write_ppm(&mut out, &screen, &palette, 2).unwrap();
+
+        // the screen only reaches as far as the ball, two tiles wide
+        let header = "P6\n4 2\n255\n";
+        assert!(out.starts_with(header.as_bytes()));
+        let pixels = &out[header.len()..];
+        assert_eq!(pixels.len(), 4 * 2 * 3);
+        // the second tile of the first two rows is the ball
+        let row = 4 * 3;
+        assert_eq!(&pixels[0..3], &palette.empty);
+        assert_eq!(&pixels[6..12], &[255; 6]);
+        assert_eq!(&pixels[row + 6..row + 12], &[255; 6]);
+    }
+}
@@ -0,0 +1,312 @@
+use std::io::{self, Read, Stdout, Write};
+use std::process::{Command, Stdio};
+use std::sync::mpsc::{self, Receiver};
+use std::thread;
+
+#[cfg(feature = "ncurses")]
+use ncurses::*;
+
+use crate::arcade::Screen;
+
+pub trait Frontend {
+    fn draw(&mut self, screen: &Screen, score: i64);
+
+    fn message(&mut self, text: &str);
+
+    // a line of game state (score, speed, ...) for frontends with room for it
+    fn status(&mut self, _text: &str) {}
+
+    // the last key pressed, for frontends that have a keyboard
+    fn key(&mut self) -> Option<char> {
+        None
+    }
+}
+
+// draws to several frontends at once, e.g. the terminal and an export
+impl Frontend for Vec<Box<dyn Frontend>> {
+    fn draw(&mut self, screen: &Screen, score: i64) {
+        for frontend in self.iter_mut() {
+            frontend.draw(screen, score);
+        }
+    }
+
+    fn message(&mut self, text: &str) {
+        for frontend in self.iter_mut() {
+            frontend.message(text);
+        }
+    }
+
+    fn status(&mut self, text: &str) {
+        for frontend in self.iter_mut() {
+            frontend.status(text);
+        }
+    }
+
+    fn key(&mut self) -> Option<char> {
+        self.iter_mut().filter_map(|frontend| frontend.key()).last()
+    }
+}
+
+// Plain text frames, one after another, for tests and terminals without
+// curses support.
+pub struct Headless<W: Write> {
+    out: W,
+}
+
+impl<W: Write> Headless<W> {
+    pub fn new(out: W) -> Headless<W> {
+        Headless { out }
+    }
+
+    pub fn into_inner(self) -> W {
+        self.out
+    }
+}
+
+impl<W: Write> Frontend for Headless<W> {
+    fn draw(&mut self, screen: &Screen, score: i64) {
+        writeln!(self.out, "score: {}", score).unwrap();
+        for row in screen.rows() {
+            let line: String = row.iter().map(|tile| tile.symbol()).collect();
+            writeln!(self.out, "{}", line.trim_end()).unwrap();
+        }
+    }
+
+    fn message(&mut self, text: &str) {
+        writeln!(self.out, "{}", text).unwrap();
+    }
+}
+
+// Draws with plain ANSI escape codes, so it needs nothing but a terminal.
+pub struct Ansi<W: Write> {
+    out: W,
+    keys: Option<Receiver<char>>,
+    _tty: Option<Tty>,
+    status_row: usize,
+}
+
+// Takes the terminal out of line mode (via `stty`, so no libc bindings are
+// needed) and hides the cursor, putting both back when dropped.
+struct Tty {
+    saved: String,
+}
+
+impl Tty {
+    fn new() -> Option<Tty> {
+        let saved = stty(&["-g"])?;
+        stty(&["-icanon", "-echo", "min", "1"])?;
+        print!("\x1b[?25l\x1b[2J");
+        Some(Tty {
+            saved: saved.trim().to_string(),
+        })
+    }
+}
+
+impl Drop for Tty {
+    fn drop(&mut self) {
+        println!("\x1b[?25h");
+        io::stdout().flush().ok();
+        stty(&[&self.saved]);
+    }
+}
+
+fn stty(args: &[&str]) -> Option<String> {
+    let output = Command::new("stty")
+        .args(args)
+        .stdin(Stdio::inherit())
+        .output()
+        .ok()?;
+    if output.status.success() {
+        String::from_utf8(output.stdout).ok()
+    } else {
+        None
+    }
+}
+
+impl Ansi<Stdout> {
+    // the real terminal, reading keys from stdin on a background thread
+    pub fn terminal() -> Ansi<Stdout> {
+        let tty = Tty::new();
+        let (send, keys) = mpsc::channel();
+        thread::spawn(move || {
+            // a byte at a time, so each key arrives as soon as it's pressed
+            let mut byte = [0];
+            while let Ok(1) = io::stdin().read(&mut byte) {
+                if send.send(byte[0] as char).is_err() {
+                    break;
+                }
+            }
+        });
+        Ansi {
+            out: io::stdout(),
+            keys: Some(keys),
+            _tty: tty,
+            status_row: 0,
+        }
+    }
+}
+
+impl Default for Ansi<Stdout> {
+    fn default() -> Ansi<Stdout> {
+        Ansi::terminal()
+    }
+}
+
+impl<W: Write> Ansi<W> {
+    // draws to `out` without touching the terminal or reading keys
+    pub fn new(out: W) -> Ansi<W> {
+        Ansi {
+            out,
+            keys: None,
+            _tty: None,
+            status_row: 0,
+        }
+    }
+
+    pub fn into_inner(self) -> W {
+        self.out
+    }
+
+    // rows are counted from 0, the terminal counts from 1
+    fn line(&mut self, row: usize, text: &str) {
+        write!(self.out, "\x1b[{};1H{}\x1b[K", row + 1, text).unwrap();
+    }
+}
+
+impl<W: Write> Frontend for Ansi<W> {
+    fn draw(&mut self, screen: &Screen, score: i64) {
+        self.line(0, &format!("score: {} ----------------------", score));
+        for (y, row) in screen.rows().iter().enumerate() {
+            let line: String = row.iter().map(|tile| tile.symbol()).collect();
+            self.line(y + 1, &line);
+        }
+        self.status_row = screen.rows().len() + 1;
+        self.out.flush().unwrap();
+    }
+
+    // shows the text under the status line and waits for a key
+    fn message(&mut self, text: &str) {
+        self.line(self.status_row + 1, text);
+        self.out.flush().unwrap();
+        if let Some(keys) = &self.keys {
+            keys.recv().ok();
+        }
+    }
+
+    fn status(&mut self, text: &str) {
+        self.line(self.status_row, text);
+        self.out.flush().unwrap();
+    }
+
+    fn key(&mut self) -> Option<char> {
+        self.keys.as_ref()?.try_recv().ok()
+    }
+}
+
+#[cfg(feature = "ncurses")]
+pub struct Ncurses {
+    status_row: i32,
+}
+
+#[cfg(feature = "ncurses")]
+impl Ncurses {
+    pub fn new() -> Ncurses {
+        initscr();
+        noecho();
+        curs_set(CURSOR_VISIBILITY::CURSOR_INVISIBLE);
+        timeout(0);
+        clear();
+        Ncurses { status_row: 0 }
+    }
+}
+
+#[cfg(feature = "ncurses")]
+impl Default for Ncurses {
+    fn default() -> Ncurses {
+        Ncurses::new()
+    }
+}
+
+#[cfg(feature = "ncurses")]
+impl Frontend for Ncurses {
+    fn draw(&mut self, screen: &Screen, score: i64) {
+        mvprintw(0, 0, &format!("score: {} ----------------------", score));
+        for (y, row) in screen.rows().iter().enumerate() {
+            let line: String = row.iter().map(|tile| tile.symbol()).collect();
+            mvprintw(y as i32 + 1, 0, &line);
+        }
+        self.status_row = screen.rows().len() as i32 + 1;
+        refresh();
+    }
+
+    // shows the text under the board and waits for a key
+    fn message(&mut self, text: &str) {
+        let mut rows = 0;
+        let mut cols = 0;
+        getmaxyx(stdscr(), &mut rows, &mut cols);
+        mvprintw(rows - 1, 0, text);
+        clrtoeol();
+        refresh();
+        timeout(-1);
+        getch();
+        timeout(0);
+    }
+
+    fn status(&mut self, text: &str) {
+        mvprintw(self.status_row, 0, text);
+        clrtoeol();
+        refresh();
+    }
+
+    fn key(&mut self) -> Option<char> {
+        match getch() {
+            ERR => None,
+            key => std::char::from_u32(key as u32),
+        }
+    }
+}
+
+#[cfg(feature = "ncurses")]
+impl Drop for Ncurses {
+    fn drop(&mut self) {
+        endwin();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::arcade::Tile;
+
+    #[test]
+    fn test_headless_frame() {
+        let mut screen = Screen::new();
+        screen.set(0, 0, Tile::Wall);
+        screen.set(1, 0, Tile::Block);
+        screen.set(2, 1, Tile::Ball);
+        screen.set(1, 2, Tile::Paddle);
+
+        let mut frontend = Headless::new(Vec::new());
+        frontend.draw(&screen, 12);
+        let text = String::from_utf8(frontend.into_inner()).unwrap();
+        let lines: Vec<&str> = text.lines().take(4).collect();
+        assert_eq!(lines, vec!["score: 12", "|X", "  O", " ="]);
+    }
+
+    #[test]
+    fn test_ansi_frame() {
+        let mut screen = Screen::new();
+        screen.set(1, 0, Tile::Ball);
+
+        let mut frontend = Ansi::new(Vec::new());
+        frontend.draw(&screen, 3);
+        frontend.status("paused");
+        frontend.message("game over");
+        let text = String::from_utf8(frontend.into_inner()).unwrap();
+        assert_eq!(
+            text,
+            "\x1b[1;1Hscore: 3 ----------------------\x1b[K\x1b[2;1H O\x1b[K\
+             \x1b[3;1Hpaused\x1b[K\x1b[4;1Hgame over\x1b[K"
+        );
+    }
+}
@@ -0,0 +1,317 @@
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::arcade::{Arcade, Tile};
+use crate::controller::JoystickController;
+use crate::frontend::Frontend;
+use crate::history::History;
+use crate::recording::Recording;
+
+pub const QUIT: char = 'q';
+pub const PAUSE: char = 'p';
+pub const STEP: char = 'n';
+pub const FASTER: char = '+';
+pub const SLOWER: char = '-';
+pub const SAVE: char = 'w';
+pub const RESTORE: char = 'r';
+pub const REWIND: char = 'u';
+
+#[derive(Clone)]
+struct Snapshot {
+    arcade: Arcade,
+    recorded: usize,
+    ticks: usize,
+}
+
+// Runs the arcade against a controller and a frontend, at a fixed number of
+// frames per second (or as fast as it can), recording everything.
+//
+// Hotkeys: q quits, p pauses and resumes, n steps one tick while paused,
+// + and - double and halve the speed, w saves the game, r restores the save
+// and u rewinds to the last automatic snapshot (taken every 25 ticks, 50
+// deep). Hotkeys win over a keyboard controller using the same keys.
+pub struct Game {
+    arcade: Arcade,
+    recording: Recording,
+    ticks: usize,
+    fps: Option<f64>,
+    paused: bool,
+    wait_at_game_over: bool,
+    saved: Option<Snapshot>,
+    history: History<Snapshot>,
+}
+
+impl Game {
+    pub fn new(rom: &[i64]) -> Game {
+        Game::from_arcade(Arcade::new(rom))
+    }
+
+    // Picks up a game already in progress, e.g. one patched by a cheat. The
+    // recording starts from this arcade's screen, so it only replays against
+    // the same starting point.
+    pub fn from_arcade(arcade: Arcade) -> Game {
+        let mut recording = Recording::new();
+        recording.record_updates(&arcade);
+        Game {
+            arcade,
+            recording,
+            ticks: 0,
+            fps: None,
+            paused: false,
+            wait_at_game_over: false,
+            saved: None,
+            history: History::new(50, 25),
+        }
+    }
+
+    // None runs the game as fast as it can, and so does a rate of 0 or less
+    pub fn fps(mut self, fps: Option<f64>) -> Game {
+        self.fps = fps.filter(|fps| fps.is_finite() && *fps > 0.0);
+        self
+    }
+
+    // keep the game open after the ball is lost, so a player can rewind
+    pub fn wait_at_game_over(mut self, wait: bool) -> Game {
+        self.wait_at_game_over = wait;
+        self
+    }
+
+    pub fn arcade(&self) -> &Arcade {
+        &self.arcade
+    }
+
+    pub fn recording(&self) -> &Recording {
+        &self.recording
+    }
+
+    pub fn ticks(&self) -> usize {
+        self.ticks
+    }
+
+    pub fn status(&self) -> String {
+        let blocks = self.arcade.screen().count(Tile::Block);
+        let speed = match self.fps {
+            Some(fps) => format!("{} fps", fps),
+            None => "max speed".to_string(),
+        };
+        let state = match self.arcade.error() {
+            Some(e) => format!(" | {}", e),
+            None if !self.arcade.is_running() => " | game over".to_string(),
+            None if self.paused => " | paused".to_string(),
+            None => String::new(),
+        };
+        format!(
+            "score: {} | blocks: {} | tick: {} | {}{}",
+            self.arcade.score(),
+            blocks,
+            self.ticks,
+            speed,
+            state
+        )
+    }
+
+    pub fn run(&mut self, frontend: &mut dyn Frontend, controller: &mut dyn JoystickController) {
+        let mut next_frame = Instant::now();
+        let mut redraw = true;
+        loop {
+            if redraw {
+                frontend.draw(self.arcade.screen(), self.arcade.score());
+                frontend.status(&self.status());
+                redraw = false;
+            }
+
+            let key = frontend.key();
+            let handled = match key {
+                Some(QUIT) => break,
+                Some(PAUSE) => {
+                    self.paused = !self.paused;
+                    true
+                }
+                Some(FASTER) => {
+                    self.fps = self.fps.map(|fps| fps * 2.0);
+                    true
+                }
+                Some(SLOWER) => {
+                    self.fps = self.fps.map(|fps| (fps / 2.0).max(0.5));
+                    true
+                }
+                Some(SAVE) => {
+                    self.saved = Some(self.snapshot());
+                    true
+                }
+                Some(RESTORE) => {
+                    if let Some(snapshot) = self.saved.clone() {
+                        self.restore(snapshot);
+                    }
+                    true
+                }
+                Some(REWIND) => {
+                    if let Some(snapshot) = self.history.rewind() {
+                        self.restore(snapshot);
+                    }
+                    true
+                }
+                _ => false,
+            };
+            if handled {
+                redraw = true;
+                continue;
+            }
+
+            if !self.arcade.is_running() {
+                if !self.wait_at_game_over {
+                    break;
+                }
+                thread::sleep(Duration::from_millis(10));
+                continue;
+            }
+            if self.paused && key != Some(STEP) {
+                thread::sleep(Duration::from_millis(10));
+                next_frame = Instant::now();
+                continue;
+            }
+            if let Some(fps) = self.fps {
+                next_frame += Duration::from_secs_f64(1.0 / fps);
+                let now = Instant::now();
+                if next_frame > now {
+                    thread::sleep(next_frame - now);
+                } else {
+                    next_frame = now;
+                }
+            }
+
+            let (arcade, recording, ticks) = (&self.arcade, &self.recording, self.ticks);
+            self.history.record(|| Snapshot {
+                arcade: arcade.clone(),
+                recorded: recording.entries.len(),
+                ticks,
+            });
+            let joystick = controller.joystick(&self.arcade, key);
+            self.recording.record_joystick(joystick);
+            self.arcade.tick(joystick);
+            self.recording.record_updates(&self.arcade);
+            self.ticks += 1;
+            redraw = true;
+        }
+    }
+
+    // snapshots remember how much had been recorded, so a restored game
+    // still replays
+    fn snapshot(&self) -> Snapshot {
+        Snapshot {
+            arcade: self.arcade.clone(),
+            recorded: self.recording.entries.len(),
+            ticks: self.ticks,
+        }
+    }
+
+    fn restore(&mut self, snapshot: Snapshot) {
+        self.arcade = snapshot.arcade;
+        self.recording.entries.truncate(snapshot.recorded);
+        self.recording.score = self.arcade.score();
+        self.ticks = snapshot.ticks;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::controller::Keyboard;
+    use crate::frontend::Headless;
+
+    // moves the ball right by the joystick input, ten times
+    fn rom() -> Vec<i64> {
+        let mut rom = vec![
+            4, 100, 104, 0, 104, 4,
+            3, 101,
+            1, 100, 101, 100,
+            104, -1, 104, 0, 4, 100,
+            1001, 102, -1, 102,
+            1005, 102, 0,
+            99,
+        ];
+        rom.resize(100, 0);
+        rom.extend(&[0, 0, 10]);
+        rom
+    }
+
+    // a headless frontend with scripted key presses
+    struct Keys(Vec<Option<char>>);
+
+    impl Frontend for Keys {
+        fn draw(&mut self, _screen: &crate::arcade::Screen, _score: i64) {}
+
+        fn message(&mut self, _text: &str) {}
+
+        fn key(&mut self) -> Option<char> {
+            if self.0.is_empty() {
+                None
+            } else {
+                self.0.remove(0)
+            }
+        }
+    }
+
+    #[test]
+    fn test_plays_to_the_end() {
+        let mut game = Game::new(&rom());
+        game.run(&mut Keys(vec![]), &mut Keyboard::new('a', 'd'));
+        assert!(!game.arcade().is_running());
+        assert_eq!(game.ticks(), 10);
+        assert_eq!(game.recording().joysticks().count(), 10);
+    }
+
+    #[test]
+    fn test_zero_fps() {
+        for fps in &[0.0, -1.0, f64::NAN, f64::INFINITY] {
+            let mut game = Game::new(&rom()).fps(Some(*fps));
+            game.run(&mut Keys(vec![]), &mut Keyboard::new('a', 'd'));
+            assert!(!game.arcade().is_running());
+            assert!(game.status().contains("max speed"));
+        }
+    }
+
+    #[test]
+    fn test_pause_and_step() {
+        let mut game = Game::new(&rom());
+        let keys = vec![Some('d'), Some(PAUSE), None, Some(STEP), None, Some(QUIT)];
+        game.run(&mut Keys(keys), &mut Keyboard::new('a', 'd'));
+        assert_eq!(game.ticks(), 2);
+        assert_eq!(game.arcade().score(), 1);
+        assert!(game.status().contains("paused"));
+    }
+
+    #[test]
+    fn test_save_and_restore() {
+        let mut game = Game::new(&rom());
+        let keys = vec![
+            Some('d'), Some(SAVE), Some('d'), Some('d'), Some(RESTORE),
+            Some(PAUSE), Some(QUIT),
+        ];
+        game.run(&mut Keys(keys), &mut Keyboard::new('a', 'd'));
+        assert_eq!(game.ticks(), 1);
+        assert_eq!(game.arcade().score(), 1);
+        assert_eq!(game.recording().joysticks().collect::<Vec<_>>(), vec![1]);
+    }
+
+    #[test]
+    fn test_rewind_after_game_over() {
+        let mut game = Game::new(&rom()).wait_at_game_over(true);
+        let mut keys = vec![Some('d'); 10];
+        keys.extend(vec![Some(REWIND), Some(REWIND), Some(QUIT)]);
+        game.run(&mut Keys(keys), &mut Keyboard::new('a', 'd'));
+        assert!(game.arcade().is_running());
+        assert_eq!(game.ticks(), 0);
+        assert_eq!(game.arcade().score(), 0);
+    }
+
+    #[test]
+    fn test_replay_quit_game() {
+        let mut game = Game::new(&rom());
+        let keys = vec![Some('d'), Some('d'), Some('a'), Some(QUIT)];
+        game.run(&mut Keys(keys), &mut Keyboard::new('a', 'd'));
+        assert!(game.arcade().is_running());
+        let replayed = game.recording().replay(&rom(), &mut Headless::new(Vec::new()));
+        assert_eq!(replayed, Ok(game.arcade().score()));
+    }
+}
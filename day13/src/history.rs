@@ -0,0 +1,83 @@
+use std::collections::VecDeque;
+
+// A rolling history of snapshots: every `interval` calls to `record` takes a
+// snapshot, dropping the oldest once there are `capacity` of them.
+pub struct History<T> {
+    snapshots: VecDeque<T>,
+    capacity: usize,
+    interval: usize,
+    ticks: usize,
+}
+
+impl<T: Clone> History<T> {
+    pub fn new(capacity: usize, interval: usize) -> History<T> {
+        History {
+            snapshots: VecDeque::with_capacity(capacity),
+            capacity,
+            interval,
+            ticks: 0,
+        }
+    }
+
+    pub fn record<F: FnOnce() -> T>(&mut self, snapshot: F) {
+        if self.ticks.is_multiple_of(self.interval) {
+            if self.snapshots.len() == self.capacity {
+                self.snapshots.pop_front();
+            }
+            self.snapshots.push_back(snapshot());
+        }
+        self.ticks += 1;
+    }
+
+    // Takes the most recent snapshot, so rewinding again goes further back.
+    // The oldest one stays put so there's always somewhere to rewind to.
+    pub fn rewind(&mut self) -> Option<T> {
+        if self.snapshots.len() > 1 {
+            self.snapshots.pop_back()
+        } else {
+            self.snapshots.back().cloned()
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.snapshots.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.snapshots.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_keeps_every_interval() {
+        let mut history = History::new(3, 2);
+        for i in 0..10 {
+            history.record(|| i);
+        }
+        assert_eq!(history.len(), 3);
+        assert_eq!(history.rewind(), Some(8));
+        assert_eq!(history.rewind(), Some(6));
+        assert_eq!(history.rewind(), Some(4));
+        assert_eq!(history.rewind(), Some(4));
+    }
+
+    #[test]
+    fn test_record_after_rewind() {
+        let mut history = History::new(10, 3);
+        assert_eq!(history.rewind(), None);
+        for i in 0..7 {
+            history.record(|| i);
+        }
+        assert_eq!(history.rewind(), Some(6));
+        // snapshots carry on every third record, counting from the start
+        for i in 100..104 {
+            history.record(|| i);
+        }
+        assert_eq!(history.rewind(), Some(102));
+        assert_eq!(history.rewind(), Some(3));
+    }
+}
@@ -1,3 +1,14 @@
+pub mod analyzer;
+pub mod arcade;
+pub mod controller;
+pub mod event;
+pub mod export;
+pub mod frontend;
+pub mod game;
+pub mod history;
+pub mod recording;
+
+#[derive(Clone)]
 pub struct IntCode {
     memory: Vec<i64>,
     counter: usize,
@@ -16,6 +27,12 @@ pub enum IoOperation {
     Write(i64),
 }
 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Status {
+    Waiting,
+    Halted,
+}
+
 #[derive(Debug)]
 enum Instruction {
     Add(Mode, Mode, Mode),
@@ -80,6 +97,14 @@ impl IntCode {
         self.memory.resize(size, 0);
     }
 
+    pub fn memory(&self) -> &[i64] {
+        &self.memory
+    }
+
+    pub fn poke(&mut self, address: usize, value: i64) {
+        self.memory[address] = value;
+    }
+
     fn get_index(&self, mode: Mode, index: usize) -> usize {
         match mode {
             Mode::Position => self.memory[self.counter + index] as usize,
@@ -115,67 +140,105 @@ impl IntCode {
         F: FnMut(IoOperation) -> i64,
     {
         while self.counter < self.memory.len() {
-            match Instruction::parse(self.memory[self.counter]) {
-                Instruction::Add(a, b, c) => {
-                    let dest = self.get_index(c, 3);
-                    self.memory[dest] = self.get(a, 1) + self.get(b, 2);
-                    self.counter += 4;
-                }
-                Instruction::Multiply(a, b, c) => {
-                    let dest = self.get_index(c, 3);
-                    self.memory[dest] = self.get(a, 1) * self.get(b, 2);
-                    self.counter += 4;
-                }
-                Instruction::Input(a) => {
-                    let i1 = self.get_index(a, 1);
-                    self.memory[i1] = io(IoOperation::Read);
-                    self.counter += 2;
-                }
-                Instruction::Output(a) => {
-                    io(IoOperation::Write(self.get(a, 1)));
-                    self.counter += 2;
-                }
-                Instruction::JumpIfTrue(a, b) => {
-                    if self.get(a, 1) != 0 {
-                        self.counter = self.get(b, 2) as usize;
-                    } else {
-                        self.counter += 3;
-                    }
+            if !self.step(&mut io) {
+                break;
+            }
+        }
+    }
+
+    // Runs until the program halts or wants input it hasn't been given.
+    // `input` is used for the first read, so resuming a waiting program with
+    // Some(value) feeds the read it stopped on.
+    pub fn resume<F>(&mut self, input: Option<i64>, mut output: F) -> Status
+    where
+        F: FnMut(i64),
+    {
+        let mut input = input;
+        while self.counter < self.memory.len() {
+            if let Instruction::Input(_) = Instruction::parse(self.memory[self.counter]) {
+                if input.is_none() {
+                    return Status::Waiting;
                 }
-                Instruction::JumpIfFalse(a, b) => {
-                    if self.get(a, 1) == 0 {
-                        self.counter = self.get(b, 2) as usize;
-                    } else {
-                        self.counter += 3;
-                    }
+            }
+            let running = self.step(|op| match op {
+                IoOperation::Read => input.take().unwrap(),
+                IoOperation::Write(val) => {
+                    output(val);
+                    0
                 }
-                Instruction::LessThan(a, b, c) => {
-                    let i3 = self.get_index(c, 3);
-                    if self.get(a, 1) < self.get(b, 2) {
-                        self.memory[i3] = 1;
-                    } else {
-                        self.memory[i3] = 0;
-                    }
-                    self.counter += 4;
+            });
+            if !running {
+                break;
+            }
+        }
+        Status::Halted
+    }
+
+    fn step<F>(&mut self, mut io: F) -> bool
+    where
+        F: FnMut(IoOperation) -> i64,
+    {
+        match Instruction::parse(self.memory[self.counter]) {
+            Instruction::Add(a, b, c) => {
+                let dest = self.get_index(c, 3);
+                self.memory[dest] = self.get(a, 1) + self.get(b, 2);
+                self.counter += 4;
+            }
+            Instruction::Multiply(a, b, c) => {
+                let dest = self.get_index(c, 3);
+                self.memory[dest] = self.get(a, 1) * self.get(b, 2);
+                self.counter += 4;
+            }
+            Instruction::Input(a) => {
+                let i1 = self.get_index(a, 1);
+                self.memory[i1] = io(IoOperation::Read);
+                self.counter += 2;
+            }
+            Instruction::Output(a) => {
+                io(IoOperation::Write(self.get(a, 1)));
+                self.counter += 2;
+            }
+            Instruction::JumpIfTrue(a, b) => {
+                if self.get(a, 1) != 0 {
+                    self.counter = self.get(b, 2) as usize;
+                } else {
+                    self.counter += 3;
                 }
-                Instruction::Equals(a, b, c) => {
-                    let i3 = self.get_index(c, 3);
-                    if self.get(a, 1) == self.get(b, 2) {
-                        self.memory[i3] = 1;
-                    } else {
-                        self.memory[i3] = 0;
-                    }
-                    self.counter += 4;
+            }
+            Instruction::JumpIfFalse(a, b) => {
+                if self.get(a, 1) == 0 {
+                    self.counter = self.get(b, 2) as usize;
+                } else {
+                    self.counter += 3;
                 }
-                Instruction::AdjustRelative(a) => {
-                    self.relative_base += self.get(a, 1);
-                    self.counter += 2;
+            }
+            Instruction::LessThan(a, b, c) => {
+                let i3 = self.get_index(c, 3);
+                if self.get(a, 1) < self.get(b, 2) {
+                    self.memory[i3] = 1;
+                } else {
+                    self.memory[i3] = 0;
                 }
-                Instruction::Break => {
-                    break;
+                self.counter += 4;
+            }
+            Instruction::Equals(a, b, c) => {
+                let i3 = self.get_index(c, 3);
+                if self.get(a, 1) == self.get(b, 2) {
+                    self.memory[i3] = 1;
+                } else {
+                    self.memory[i3] = 0;
                 }
-            };
-        }
+                self.counter += 4;
+            }
+            Instruction::AdjustRelative(a) => {
+                self.relative_base += self.get(a, 1);
+                self.counter += 2;
+            }
+            Instruction::Break => {
+                return false;
+            }
+        };
+        true
     }
 }
 
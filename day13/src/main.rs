@@ -1,123 +1,165 @@
-use std::{thread, time};
-use day13::{IntCode, IoOperation};
-use ncurses::*;
+use std::env;
+use std::fs::File;
+use std::io::{self, BufWriter};
 
-mod input;
+use day13::analyzer::{self, Analyzer, QUANTITIES};
+use day13::arcade::{Arcade, Tile};
+use day13::controller::{FollowBall, JoystickController, Keyboard, Neutral, Predictive, Replay};
+use day13::export::{Asciicast, Palette, PpmFrames};
+use day13::frontend::{Frontend, Headless};
+#[cfg(not(feature = "ncurses"))]
+use day13::frontend::Ansi as Terminal;
+#[cfg(feature = "ncurses")]
+use day13::frontend::Ncurses as Terminal;
+use day13::game::Game;
+use day13::recording::Recording;
 
-const WIDTH: usize = 50;
-const HEIGHT: usize = 25;
+mod input;
 
+// usage: day13 [--headless] [--record FILE | --replay FILE] [--cast FILE] [--ppm DIR]
+//              [--controller follow|predict|keyboard|replay:FILE] [--keys LR] [--fps N]
+//              [--cheat wide,lives,score]
 fn main() {
-    initscr();
-    curs_set(CURSOR_VISIBILITY::CURSOR_INVISIBLE);
-    clear();
+    let args: Vec<String> = env::args().collect();
+    let option = |name: &str| {
+        let i = args.iter().position(|arg| arg == name)?;
+        args.get(i + 1).cloned()
+    };
+
+    let mut controller: Box<dyn JoystickController> = match option("--controller").as_deref() {
+        None | Some("follow") => Box::new(FollowBall),
+        Some("predict") => Box::new(Predictive),
+        Some("keyboard") => match option("--keys") {
+            Some(keys) if keys.chars().count() == 2 => {
+                let keys: Vec<char> = keys.chars().collect();
+                Box::new(Keyboard::new(keys[0], keys[1]))
+            }
+            Some(keys) => panic!("--keys needs a left and a right key, not {:?}", keys),
+            None => Box::new(Keyboard::default()),
+        },
+        Some(name) if name.starts_with("replay:") => {
+            let recording = Recording::load(&name["replay:".len()..]).unwrap();
+            Box::new(Replay::new(&recording))
+        }
+        Some(name) => panic!("unknown controller {:?}", name),
+    };
+    let human = option("--controller").as_deref() == Some("keyboard");
+    // --fps 0 plays as fast as it can, like no --fps for a computer player
+    let fps = match option("--fps") {
+        Some(fps) => Some(fps.parse::<f64>().unwrap()),
+        None if human => Some(15.0),
+        None => None,
+    };
+
+    let mut frontends: Vec<Box<dyn Frontend>> = Vec::new();
+    if let Some(path) = option("--cast") {
+        // the board the game boots up to, plus the score and status lines
+        let screen = Arcade::new(&input::ORIGINAL).screen().clone();
+        let out = BufWriter::new(File::create(path).unwrap());
+        let cast = Asciicast::new(out, screen.width(), screen.height() + 2, 30.0).unwrap();
+        frontends.push(Box::new(cast));
+    }
+    if let Some(dir) = option("--ppm") {
+        frontends.push(Box::new(PpmFrames::new(&dir, Palette::default(), 4).unwrap()));
+    }
+    if args.iter().any(|arg| arg == "--headless") {
+        frontends.push(Box::new(Headless::new(io::stdout())));
+    } else {
+        frontends.push(Box::new(Terminal::default()));
+    }
+    let frontend = &mut frontends;
 
-    part_1();
-    getch();
-    part_2();
+    if let Some(path) = option("--replay") {
+        let result = Recording::load(&path).and_then(|recording| {
+            recording.replay(&quarters(), frontend)
+        });
+        match result {
+            Ok(score) => frontend.message(&format!("replay matches, final score: {}", score)),
+            Err(e) => frontend.message(&format!("replay failed: {}", e)),
+        }
+        return;
+    }
 
-    getch();
-    endwin();
+    let bricks = part_1();
+    frontend.message(&format!("number of bricks: {}", bricks));
+    let game = match option("--cheat") {
+        Some(cheats) => match cheat(&cheats, frontend) {
+            Some(arcade) => {
+                if cheats.split(',').any(|cheat| cheat == "wide") {
+                    controller = Box::new(Neutral);
+                }
+                Game::from_arcade(arcade)
+            }
+            None => return,
+        },
+        None => Game::new(&quarters()),
+    };
+    let mut game = game.fps(fps).wait_at_game_over(human);
+    game.run(frontend, controller.as_mut());
+    if let Some(path) = option("--record") {
+        game.recording().save(&path).unwrap();
+    }
+    if let Some(e) = game.arcade().error() {
+        frontend.message(e);
+    }
+    frontend.message(&format!("final score: {}", game.arcade().score()));
 }
 
-fn part_2() -> i64 {
-    let memory = &mut input::ORIGINAL.clone();
+fn quarters() -> Vec<i64> {
+    let mut memory = input::ORIGINAL.to_vec();
     memory[0] = 2;
+    memory
+}
 
-    let (mut x, mut y, mut count) = (0, 0, 0);
+// Watches a few hundred ticks of a game to find where the ROM keeps things,
+// then patches it. Returns the patched arcade, or None if there's nothing left
+// to play.
+fn cheat(cheats: &str, frontend: &mut dyn Frontend) -> Option<Arcade> {
+    let mut arcade = Arcade::new(&quarters());
+    let mut analyzer = Analyzer::new();
+    analyzer.watch(&mut arcade, &mut Predictive, 500);
 
-    let mut program = IntCode::new(memory);
-    program.biggen(10_000);
+    let mut found = Vec::new();
+    for quantity in &QUANTITIES {
+        match analyzer.locate(*quantity) {
+            Some(address) => found.push(format!("{:?} at {}", quantity, address)),
+            None => found.push(format!("{:?} not found", quantity)),
+        }
+    }
+    let map = analyzer.locate_tiles(&arcade).expect("no tile map in memory");
+    found.push(format!("tiles at {} ({}x{})", map.base, map.width, map.height));
+    frontend.message(&found.join(", "));
 
-    let mut joystick = 0;
-    let mut score = 0;
-    let mut ballpos = 0;
-    let mut padpos = 0;
-    program.run_fn(|op| match op {
-        IoOperation::Read => {
-            // uncomment this section to play yourself
-            // match getch() {
-            //     108 => 1,
-            //     115 => -1,
-            //     _ => 0,
-            // }
-            if padpos > ballpos {
-                -1
-            } else if ballpos > padpos {
-                1
-            } else {
-                0
+    let mut play = false;
+    for cheat in cheats.split(',') {
+        match cheat {
+            "wide" => {
+                analyzer::widen_paddle(&mut arcade, &map);
+                play = true;
             }
+            "lives" => {
+                analyzer::infinite_lives(&mut arcade, &map);
+                play = true;
+            }
+            "score" => match analyzer.locate_score_table(&arcade, &map) {
+                Some(table) => frontend.message(&format!(
+                    "score table at {}, final score: {}",
+                    table.base,
+                    table.final_score(&arcade)
+                )),
+                None => frontend.message("score table not found"),
+            },
+            _ => panic!("unknown cheat {:?}", cheat),
         }
-        IoOperation::Write(val) => {
-            count += 1;
-            match count {
-                1 => x = val,
-                2 => y = val,
-                _ => {
-                    if x == -1 && y == 0 {
-                        score = val;
-                        mvprintw(0, 0, &format!("score: {} ----------------------\n", score));
-                        refresh();
-                        // thread::sleep(time::Duration::from_millis(10));
-                    } else {
-                        if val == 3 {
-                            padpos = x;
-                        } else if val == 4 {
-                            ballpos = x;
-                        }
-                        mvprintw(
-                            y as i32,
-                            x as i32,
-                            match val {
-                                0 => " ",
-                                1 => "|",
-                                2 => "X",
-                                3 => "X",
-                                4 => "O",
-                                _ => "?",
-                            },
-                        );
-                    }
-                    count = 0;
-                }
-            };
-            0
-        }
-    });
-    score
+    }
+    if play {
+        Some(arcade)
+    } else {
+        None
+    }
 }
 
-fn part_1() {
-    let (mut x, mut y, mut count) = (0, 0, 0);
-    let mut panel = [[0; WIDTH]; HEIGHT];
-    let mut program = IntCode::new(&input::ORIGINAL);
-    program.biggen(10_000);
-    program.run_fn(|op| match op {
-        IoOperation::Read => 1,
-        IoOperation::Write(val) => {
-            count += 1;
-            match count {
-                1 => x = val,
-                2 => y = val,
-                _ => {
-                    panel[y as usize][x as usize] = val;
-                    count = 0;
-                }
-            };
-            0
-        }
-    });
-    mvprintw(0, 0, 
-        &format!(
-            "number of bricks: {}",
-            panel
-                .iter()
-                .map(|x| { x.iter() })
-                .flatten()
-                .filter(|x| { **x == 2 })
-                .count()
-        )
-    );
-    refresh();
+fn part_1() -> usize {
+    let arcade = Arcade::new(&input::ORIGINAL);
+    arcade.screen().count(Tile::Block)
 }
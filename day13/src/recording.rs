@@ -0,0 +1,238 @@
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, BufWriter, Write};
+
+use crate::arcade::Arcade;
+use crate::event::Event;
+use crate::frontend::Frontend;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Entry {
+    Draw(Event),
+    Joystick(i64),
+}
+
+// Everything a game drew and every joystick input it was given, in order,
+// plus the score when it stopped, whether that was game over or the player
+// quitting. Saved as one entry per line:
+//
+//     draw 1 0 2
+//     joystick -1
+//     score 15909
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Recording {
+    pub entries: Vec<Entry>,
+    pub score: i64,
+}
+
+impl Recording {
+    pub fn new() -> Recording {
+        Recording::default()
+    }
+
+    // call once after starting the arcade and after every tick
+    pub fn record_updates(&mut self, arcade: &Arcade) {
+        self.entries
+            .extend(arcade.updates().iter().map(|event| Entry::Draw(*event)));
+        self.score = arcade.score();
+    }
+
+    pub fn record_joystick(&mut self, joystick: i64) {
+        self.entries.push(Entry::Joystick(joystick));
+    }
+
+    pub fn joysticks(&self) -> impl Iterator<Item = i64> + '_ {
+        self.entries.iter().filter_map(|entry| match entry {
+            Entry::Joystick(val) => Some(*val),
+            _ => None,
+        })
+    }
+
+    pub fn write_to<W: Write>(&self, out: &mut W) -> io::Result<()> {
+        for entry in &self.entries {
+            match entry {
+                Entry::Draw(event) => {
+                    let [x, y, val] = event.triple();
+                    writeln!(out, "draw {} {} {}", x, y, val)?
+                }
+                Entry::Joystick(val) => writeln!(out, "joystick {}", val)?,
+            }
+        }
+        writeln!(out, "score {}", self.score)
+    }
+
+    pub fn read_from<R: BufRead>(input: R) -> Result<Recording, String> {
+        let mut recording = Recording::new();
+        let mut score = None;
+        for (i, line) in input.lines().enumerate() {
+            let line = line.map_err(|e| e.to_string())?;
+            let words: Vec<&str> = line.split_whitespace().collect();
+            let numbers = words
+                .iter()
+                .skip(1)
+                .map(|w| w.parse::<i64>())
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|e| format!("line {}: {}", i + 1, e))?;
+            match (words.first(), numbers.as_slice()) {
+                (None, _) => {}
+                (Some(&"draw"), &[x, y, val]) => {
+                    let event = Event::decode([x, y, val]).map_err(|e| format!("line {}: {}", i + 1, e))?;
+                    recording.entries.push(Entry::Draw(event))
+                }
+                (Some(&"joystick"), &[val]) => recording.entries.push(Entry::Joystick(val)),
+                (Some(&"score"), &[val]) => score = Some(val),
+                _ => return Err(format!("line {}: can't read {:?}", i + 1, line)),
+            }
+        }
+        recording.score = score.ok_or("recording has no final score")?;
+        Ok(recording)
+    }
+
+    pub fn save(&self, path: &str) -> io::Result<()> {
+        let mut out = BufWriter::new(File::create(path)?);
+        self.write_to(&mut out)?;
+        out.flush()
+    }
+
+    pub fn load(path: &str) -> Result<Recording, String> {
+        let file = File::open(path).map_err(|e| format!("{}: {}", path, e))?;
+        Recording::read_from(BufReader::new(file))
+    }
+
+    // Plays the recorded joystick inputs into a fresh arcade, checking every
+    // tick draws the same thing and the game stops on the same score. The
+    // replay ends where the recording does, so a session that was quit part
+    // way through replays up to that point.
+    pub fn replay(&self, rom: &[i64], frontend: &mut dyn Frontend) -> Result<i64, String> {
+        let mut arcade = Arcade::new(rom);
+        let mut expected = self.entries.iter();
+        let mut tick = 0;
+        loop {
+            frontend.draw(arcade.screen(), arcade.score());
+            if let Some(e) = arcade.error() {
+                return Err(format!("tick {}: {}", tick, e));
+            }
+            for event in arcade.updates() {
+                match expected.next() {
+                    Some(Entry::Draw(recorded)) if recorded == event => {}
+                    other => {
+                        return Err(format!(
+                            "tick {}: drew {:?}, recording has {:?}",
+                            tick, event, other
+                        ))
+                    }
+                }
+            }
+            match expected.next() {
+                Some(Entry::Joystick(val)) if arcade.is_running() => arcade.tick(*val),
+                None => break,
+                other => {
+                    return Err(format!(
+                        "tick {}: game {}, recording has {:?}",
+                        tick,
+                        if arcade.is_running() { "is running" } else { "is over" },
+                        other
+                    ))
+                }
+            }
+            tick += 1;
+        }
+        if arcade.score() != self.score {
+            return Err(format!(
+                "score is {} at the end, recording has {}",
+                arcade.score(),
+                self.score
+            ));
+        }
+        Ok(arcade.score())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::frontend::Headless;
+
+    // draws the ball at x = 1, then three times adds the joystick input to x,
+    // draws the ball there and adds x to the score
+    fn rom() -> Vec<i64> {
+        let mut rom = vec![
+            104, 1, 104, 0, 104, 4,
+            3, 102,
+            1, 100, 102, 100,
+            4, 100, 104, 0, 104, 4,
+            1, 103, 100, 103,
+            104, -1, 104, 0, 4, 103,
+            1001, 101, -1, 101,
+            1005, 101, 6,
+            99,
+        ];
+        rom.resize(100, 0);
+        rom.extend(&[1, 3, 0, 0]);
+        rom
+    }
+
+    fn play(joysticks: &[i64]) -> Recording {
+        let mut arcade = Arcade::new(&rom());
+        let mut recording = Recording::new();
+        recording.record_updates(&arcade);
+        for &joystick in joysticks {
+            recording.record_joystick(joystick);
+            arcade.tick(joystick);
+            recording.record_updates(&arcade);
+        }
+        recording
+    }
+
+    #[test]
+    fn test_save_and_load() {
+        let recording = play(&[1, 1, -1]);
+        assert_eq!(recording.score, 2 + 3 + 2);
+
+        let mut saved = Vec::new();
+        recording.write_to(&mut saved).unwrap();
+        let loaded = Recording::read_from(&saved[..]).unwrap();
+        assert_eq!(loaded, recording);
+        assert_eq!(loaded.joysticks().collect::<Vec<_>>(), vec![1, 1, -1]);
+    }
+
+    #[test]
+    fn test_replay() {
+        let recording = play(&[1, 0, 1]);
+        let mut frontend = Headless::new(Vec::new());
+        assert_eq!(recording.replay(&rom(), &mut frontend), Ok(recording.score));
+
+        let mut tampered = recording.clone();
+        tampered.score += 1;
+        assert!(tampered.replay(&rom(), &mut frontend).is_err());
+
+        let mut tampered = recording;
+        tampered.entries.retain(|entry| *entry != Entry::Joystick(0));
+        assert!(tampered.replay(&rom(), &mut frontend).is_err());
+    }
+
+    #[test]
+    fn test_replay_unfinished() {
+        // stopped after two of the three moves
+        let recording = play(&[1, 1]);
+        assert_eq!(recording.score, 2 + 3);
+        let mut frontend = Headless::new(Vec::new());
+        assert_eq!(recording.replay(&rom(), &mut frontend), Ok(5));
+
+        let mut tampered = recording.clone();
+        tampered.score = 7;
+        assert!(tampered.replay(&rom(), &mut frontend).is_err());
+
+        // a joystick input after game over is still an error
+        let mut finished = play(&[1, 1, 1]);
+        finished.entries.push(Entry::Joystick(0));
+        assert!(finished.replay(&rom(), &mut frontend).is_err());
+    }
+
+    #[test]
+    fn test_read_errors() {
+        assert!(Recording::read_from(&b"draw 1 2\nscore 0\n"[..]).is_err());
+        assert!(Recording::read_from(&b"draw 1 2 9\nscore 0\n"[..]).is_err());
+        assert!(Recording::read_from(&b"joystick x\nscore 0\n"[..]).is_err());
+        assert!(Recording::read_from(&b"joystick 1\n"[..]).is_err());
+    }
+}
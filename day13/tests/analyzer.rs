@@ -0,0 +1,50 @@
+use day13::analyzer::{self, Analyzer, Quantity, TileMap};
+use day13::arcade::{Arcade, Tile};
+use day13::controller::FollowBall;
+
+#[path = "../src/input.rs"]
+mod input;
+
+fn analyze(ticks: usize) -> (Arcade, Analyzer) {
+    let mut rom = input::ORIGINAL.to_vec();
+    rom[0] = 2;
+    let mut arcade = Arcade::new(&rom);
+    let mut analyzer = Analyzer::new();
+    analyzer.watch(&mut arcade, &mut FollowBall, ticks);
+    (arcade, analyzer)
+}
+
+#[test]
+fn test_locates_game_state() {
+    let (arcade, analyzer) = analyze(500);
+    assert_eq!(
+        analyzer.locate_tiles(&arcade),
+        Some(TileMap { base: 639, width: 42, height: 24 })
+    );
+    for quantity in &[Quantity::BallX, Quantity::BallY, Quantity::PaddleX, Quantity::Score] {
+        assert!(analyzer.locate(*quantity).is_some(), "{:?} not found", quantity);
+    }
+    let ball_x = analyzer.locate(Quantity::BallX).unwrap();
+    let ball = arcade.screen().find(Tile::Ball).unwrap();
+    assert_eq!(arcade.memory()[ball_x], ball.0);
+}
+
+#[test]
+fn test_final_score_from_table() {
+    let (arcade, analyzer) = analyze(500);
+    let map = analyzer.locate_tiles(&arcade).unwrap();
+    let table = analyzer.locate_score_table(&arcade, &map).unwrap();
+    assert_eq!(table.base, 639 + 42 * 24);
+    assert_eq!(table.final_score(&arcade), 15909);
+}
+
+#[test]
+fn test_wide_paddle_needs_no_player() {
+    let (mut arcade, analyzer) = analyze(50);
+    let map = analyzer.locate_tiles(&arcade).unwrap();
+    analyzer::widen_paddle(&mut arcade, &map);
+    while arcade.is_running() {
+        arcade.tick(0);
+    }
+    assert_eq!(arcade.score(), 15909);
+}
@@ -0,0 +1,47 @@
+use day13::arcade::{Arcade, Tile};
+use day13::controller::{FollowBall, JoystickController, Predictive, Replay};
+use day13::recording::Recording;
+
+#[path = "../src/input.rs"]
+mod input;
+
+fn play(controller: &mut dyn JoystickController) -> (Arcade, Recording) {
+    let mut rom = input::ORIGINAL.to_vec();
+    rom[0] = 2;
+    let mut arcade = Arcade::new(&rom);
+    let mut recording = Recording::new();
+    recording.record_updates(&arcade);
+    while arcade.is_running() {
+        let joystick = controller.joystick(&arcade, None);
+        recording.record_joystick(joystick);
+        arcade.tick(joystick);
+        recording.record_updates(&arcade);
+    }
+    (arcade, recording)
+}
+
+fn blocks_left(arcade: &Arcade) -> usize {
+    arcade.screen().count(Tile::Block)
+}
+
+#[test]
+fn test_follow_ball_clears_board() {
+    let (arcade, _) = play(&mut FollowBall);
+    assert_eq!(blocks_left(&arcade), 0);
+    assert_eq!(arcade.score(), 15909);
+}
+
+#[test]
+fn test_predictive_clears_board() {
+    let (arcade, _) = play(&mut Predictive);
+    assert_eq!(blocks_left(&arcade), 0);
+    assert_eq!(arcade.score(), 15909);
+}
+
+#[test]
+fn test_replay_controller_repeats_game() {
+    let (original, recording) = play(&mut Predictive);
+    let (replayed, again) = play(&mut Replay::new(&recording));
+    assert_eq!(replayed.score(), original.score());
+    assert_eq!(again, recording);
+}
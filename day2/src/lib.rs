@@ -0,0 +1,37 @@
+pub mod search;
+pub mod symbolic;
+
+// Errors rather than panicking when a program reads or writes outside memory
+// or a value overflows, since patched programs can do either.
+pub fn run_program(memory: &mut [i32]) -> Result<(), String> {
+    let cell = |memory: &[i32], address: usize| {
+        memory
+            .get(address)
+            .cloned()
+            .ok_or_else(|| format!("address {} is outside memory", address))
+    };
+    let pointer = |memory: &[i32], address: usize| {
+        let value = cell(memory, address)?;
+        if value < 0 || value as usize >= memory.len() {
+            return Err(format!("cell {} points outside memory: {}", address, value));
+        }
+        Ok(value as usize)
+    };
+    let mut i = 0;
+    while i < memory.len() {
+        match memory[i] {
+            1 | 2 => {
+                let opcode = memory[i];
+                let s1 = cell(memory, pointer(memory, i + 1)?)?;
+                let s2 = cell(memory, pointer(memory, i + 2)?)?;
+                let dest = pointer(memory, i + 3)?;
+                let value = if opcode == 1 { s1.checked_add(s2) } else { s1.checked_mul(s2) };
+                memory[dest] = value.ok_or_else(|| format!("overflow at {}", i))?;
+                i += 4;
+            },
+            99 => break,
+            _ => i += 1,
+        }
+    }
+    Ok(())
+}
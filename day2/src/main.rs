@@ -0,0 +1,32 @@
+use day2::run_program;
+use day2::search::Search;
+use day2::symbolic::Symbolic;
+
+mod input;
+
+fn main() {
+    // part 1
+    let mut part1_memory = input::ORIGINAL.to_vec();
+    run_program(&mut part1_memory).unwrap();
+    println!("{}", part1_memory[0]);
+
+    // part 2
+    let needle = 19690720;
+    let found = Search::cell(&input::ORIGINAL, 0)
+        .patch(1, 0..100)
+        .patch(2, 0..100)
+        .first(needle)
+        .unwrap();
+    if let Some(assignment) = found {
+        println!("{}, {}", assignment[0], assignment[1]);
+    }
+
+    // part 2 again, without brute force
+    let mut machine = Symbolic::new(&input::ORIGINAL, &[1, 2]).unwrap();
+    machine.run().unwrap();
+    let expr = machine.cell(0).unwrap();
+    println!("memory[0] = {}", expr);
+    for assignment in expr.solve(needle, &[(1, 0..100), (2, 0..100)]).unwrap() {
+        println!("{}, {}", assignment[0], assignment[1]);
+    }
+}
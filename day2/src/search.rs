@@ -0,0 +1,201 @@
+use std::ops::Range;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+
+use crate::run_program;
+
+// Brute-force search over patched addresses, e.g. the noun (memory[1]) and
+// verb (memory[2]) of day 2. Candidates are numbered like nested loops, with
+// the last patched address varying fastest, and split across all cores. A
+// candidate whose program faults just doesn't match.
+pub struct Search<'a> {
+    program: &'a [i32],
+    patches: Vec<(usize, Range<i32>)>,
+    evaluate: Box<dyn Fn(Vec<i32>) -> Result<i32, String> + Sync + 'a>,
+}
+
+impl<'a> Search<'a> {
+    // `evaluate` runs the patched memory however it likes and returns the
+    // value to compare against the goal (a cell, an output, ...).
+    pub fn new<F>(program: &'a [i32], evaluate: F) -> Search<'a>
+    where
+        F: Fn(Vec<i32>) -> Result<i32, String> + Sync + 'a,
+    {
+        Search {
+            program,
+            patches: Vec::new(),
+            evaluate: Box::new(evaluate),
+        }
+    }
+
+    pub fn cell(program: &'a [i32], address: usize) -> Search<'a> {
+        Search::new(program, move |mut memory| {
+            run_program(&mut memory)?;
+            memory
+                .get(address)
+                .cloned()
+                .ok_or_else(|| format!("address {} is outside memory", address))
+        })
+    }
+
+    pub fn patch(mut self, address: usize, values: Range<i32>) -> Search<'a> {
+        self.patches.push((address, values));
+        self
+    }
+
+    // both are errors when there are too many candidates to number
+    pub fn first(&self, goal: i32) -> Result<Option<Vec<i32>>, String> {
+        Ok(self
+            .run(goal, true)?
+            .first()
+            .map(|&index| self.assignment(index)))
+    }
+
+    pub fn all(&self, goal: i32) -> Result<Vec<Vec<i32>>, String> {
+        Ok(self
+            .run(goal, false)?
+            .into_iter()
+            .map(|index| self.assignment(index))
+            .collect())
+    }
+
+    fn len(&self) -> Result<usize, String> {
+        self.patches
+            .iter()
+            .try_fold(1usize, |len, (_, values)| len.checked_mul(values.len()))
+            .ok_or_else(|| String::from("too many candidates to search"))
+    }
+
+    fn assignment(&self, mut index: usize) -> Vec<i32> {
+        let mut values = vec![0; self.patches.len()];
+        for (i, (_, range)) in self.patches.iter().enumerate().rev() {
+            values[i] = range.start + (index % range.len()) as i32;
+            index /= range.len();
+        }
+        values
+    }
+
+    fn matches(&self, index: usize, goal: i32) -> bool {
+        let mut memory = self.program.to_vec();
+        for ((address, _), value) in self.patches.iter().zip(self.assignment(index)) {
+            match memory.get_mut(*address) {
+                Some(cell) => *cell = value,
+                None => return false,
+            }
+        }
+        (self.evaluate)(memory) == Ok(goal)
+    }
+
+    // Returns the matching candidate indices in ascending order. Workers take
+    // interleaved indices; once one of them finds a match with `stop_at_first`
+    // set, the others give up on anything past it, so the lowest match still
+    // wins.
+    fn run(&self, goal: i32, stop_at_first: bool) -> Result<Vec<usize>, String> {
+        let len = self.len()?;
+        let workers = thread::available_parallelism()
+            .map(|n| n.get())
+            .unwrap_or(1)
+            .min(len)
+            .max(1);
+        let limit = &AtomicUsize::new(usize::MAX);
+        let mut found: Vec<usize> = thread::scope(|scope| {
+            let handles: Vec<_> = (0..workers)
+                .map(|worker| {
+                    scope.spawn(move || {
+                        let mut found = Vec::new();
+                        for index in (worker..len).step_by(workers) {
+                            if index >= limit.load(Ordering::Relaxed) {
+                                break;
+                            }
+                            if self.matches(index, goal) {
+                                found.push(index);
+                                if stop_at_first {
+                                    limit.fetch_min(index, Ordering::Relaxed);
+                                }
+                            }
+                        }
+                        found
+                    })
+                })
+                .collect();
+            handles
+                .into_iter()
+                .flat_map(|handle| handle.join().unwrap())
+                .collect()
+        });
+        found.sort();
+        Ok(found)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // memory[0] = memory[9] * memory[10] + memory[11]
+    const PROGRAM: [i32; 12] = [2, 9, 10, 0, 1, 0, 11, 0, 99, 0, 0, 0];
+
+    #[test]
+    fn test_first_is_lowest_assignment() {
+        let found = Search::cell(&PROGRAM, 0)
+            .patch(9, 0..10)
+            .patch(10, 0..10)
+            .first(12).unwrap();
+        assert_eq!(found, Some(vec![2, 6]));
+    }
+
+    #[test]
+    fn test_all_assignments() {
+        let found = Search::cell(&PROGRAM, 0)
+            .patch(9, 1..10)
+            .patch(10, 1..10)
+            .all(12).unwrap();
+        assert_eq!(found, vec![vec![2, 6], vec![3, 4], vec![4, 3], vec![6, 2]]);
+    }
+
+    #[test]
+    fn test_no_match() {
+        let found = Search::cell(&PROGRAM, 0)
+            .patch(9, 0..3)
+            .patch(10, 0..3)
+            .first(100).unwrap();
+        assert_eq!(found, None);
+    }
+
+    #[test]
+    fn test_custom_evaluate() {
+        let found = Search::new(&PROGRAM, |memory| Ok(memory[9] - memory[10]))
+            .patch(9, 0..5)
+            .patch(10, 0..5)
+            .all(3).unwrap();
+        assert_eq!(found, vec![vec![3, 0], vec![4, 1]]);
+    }
+
+    #[test]
+    fn test_faulting_candidates() {
+        // noun and verb are pointers here, so most of 0..200 points past the
+        // end of memory
+        let program = [1, 0, 0, 0, 99, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 30];
+        let found = Search::cell(&program, 0).patch(1, 0..200).patch(2, 0..200).all(31).unwrap();
+        assert_eq!(found, vec![vec![0, 23], vec![1, 23], vec![23, 0]]);
+        assert_eq!(Search::cell(&program, 0).patch(1, 0..200).first(-1), Ok(None));
+
+        // a patch outside memory, and an overflowing product
+        assert_eq!(Search::cell(&PROGRAM, 0).patch(30, 0..5).first(0), Ok(None));
+        let found = Search::cell(&PROGRAM, 0)
+            .patch(9, i32::MAX - 1..i32::MAX)
+            .patch(10, 1..3)
+            .all(i32::MAX - 1).unwrap();
+        assert_eq!(found, vec![vec![i32::MAX - 1, 1]]);
+    }
+
+    #[test]
+    fn test_too_many_candidates() {
+        let search = Search::cell(&PROGRAM, 0)
+            .patch(9, i32::MIN..i32::MAX)
+            .patch(10, i32::MIN..i32::MAX)
+            .patch(11, i32::MIN..i32::MAX);
+        assert_eq!(search.first(0), Err(String::from("too many candidates to search")));
+        assert!(search.all(0).is_err());
+    }
+}
@@ -0,0 +1,350 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::ops::Range;
+
+// A polynomial over unknown memory cells. Each term maps a monomial (the
+// sorted addresses of the unknowns multiplied together, with repeats) to its
+// coefficient; the constant term has an empty monomial.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Poly {
+    terms: BTreeMap<Vec<usize>, i64>,
+}
+
+impl Poly {
+    pub fn constant(value: i64) -> Poly {
+        let mut terms = BTreeMap::new();
+        if value != 0 {
+            terms.insert(vec![], value);
+        }
+        Poly { terms }
+    }
+
+    pub fn unknown(address: usize) -> Poly {
+        let mut terms = BTreeMap::new();
+        terms.insert(vec![address], 1);
+        Poly { terms }
+    }
+
+    pub fn as_constant(&self) -> Option<i64> {
+        match self.terms.len() {
+            0 => Some(0),
+            1 => self.terms.get(&vec![]).cloned(),
+            _ => None,
+        }
+    }
+
+    pub fn coefficient(&self, monomial: &[usize]) -> i64 {
+        self.terms.get(monomial).cloned().unwrap_or(0)
+    }
+
+    pub fn degree(&self) -> usize {
+        self.terms.keys().map(|m| m.len()).max().unwrap_or(0)
+    }
+
+    pub fn unknowns(&self) -> Vec<usize> {
+        let mut unknowns: Vec<usize> = self.terms.keys().flatten().cloned().collect();
+        unknowns.sort();
+        unknowns.dedup();
+        unknowns
+    }
+
+    // Solves `self == goal` for a linear polynomial in at most two unknowns,
+    // with each unknown restricted to its range. Solutions list the values in
+    // the same order as `ranges`, smallest first. Ranges and values are memory
+    // cells, like the patches of a `Search`.
+    pub fn solve(&self, goal: i32, ranges: &[(usize, Range<i32>)]) -> Result<Vec<Vec<i32>>, String> {
+        if self.degree() > 1 {
+            return Err(format!("{} is not linear", self));
+        }
+        let unknowns = self.unknowns();
+        if unknowns.len() != ranges.len() || unknowns.iter().any(|u| !ranges.iter().any(|(a, _)| a == u)) {
+            return Err(format!("ranges must cover exactly the unknowns of {}", self));
+        }
+        let overflow = || format!("solving {} = {} overflows an i64", self, goal);
+        let rest = (goal as i64).checked_sub(self.coefficient(&[])).ok_or_else(overflow)?;
+        let ranges: Vec<(usize, Range<i64>)> = ranges
+            .iter()
+            .map(|(address, range)| (*address, range.start as i64..range.end as i64))
+            .collect();
+
+        let solutions = match ranges.as_slice() {
+            [] => {
+                if rest == 0 {
+                    vec![vec![]]
+                } else {
+                    vec![]
+                }
+            }
+            [(x, xs)] => {
+                let a = self.coefficient(&[*x]);
+                if rest.checked_rem(a).ok_or_else(overflow)? == 0 && xs.contains(&(rest / a)) {
+                    vec![vec![rest / a]]
+                } else {
+                    vec![]
+                }
+            }
+            [(x, xs), (y, ys)] => {
+                let a = self.coefficient(&[*x]);
+                let b = self.coefficient(&[*y]);
+                solve_two(a, b, rest, xs, ys).ok_or_else(overflow)?
+            }
+            _ => return Err(format!("can only solve for up to two unknowns, {} has {}", self, ranges.len())),
+        };
+        // every value is inside an i32 range, so converting back is safe
+        let mut solutions: Vec<Vec<i32>> = solutions
+            .into_iter()
+            .map(|values| values.into_iter().map(|value| value as i32).collect())
+            .collect();
+        solutions.sort();
+        Ok(solutions)
+    }
+}
+
+// All (x, y) in range with a*x + b*y == c, from the extended euclidean
+// algorithm: one solution (x0, y0) plus multiples of (b/d, -a/d). None if
+// any step of that overflows an i64.
+fn solve_two(a: i64, b: i64, c: i64, xs: &Range<i64>, ys: &Range<i64>) -> Option<Vec<Vec<i64>>> {
+    let (d, p, q) = extended_gcd(a, b)?;
+    if c % d != 0 {
+        return Some(vec![]);
+    }
+    let (x0, y0) = (p.checked_mul(c / d)?, q.checked_mul(c / d)?);
+    let (sx, sy) = (b / d, a.checked_neg()? / d);
+    let (lo_x, hi_x) = steps_within(x0, sx, xs)?;
+    let (lo_y, hi_y) = steps_within(y0, sy, ys)?;
+    (lo_x.max(lo_y)..=hi_x.min(hi_y))
+        .map(|k| Some(vec![x0.checked_add(k.checked_mul(sx)?)?, y0.checked_add(k.checked_mul(sy)?)?]))
+        .collect()
+}
+
+// The k for which start + k * step stays inside range.
+fn steps_within(start: i64, step: i64, range: &Range<i64>) -> Option<(i64, i64)> {
+    let (lo, hi) = (range.start.checked_sub(start)?, (range.end - 1).checked_sub(start)?);
+    if step > 0 {
+        Some((ceil_div(lo, step)?, hi.div_euclid(step)))
+    } else {
+        Some((ceil_div(hi.checked_neg()?, -step)?, lo.checked_neg()?.div_euclid(-step)))
+    }
+}
+
+fn ceil_div(a: i64, b: i64) -> Option<i64> {
+    a.checked_neg()?.div_euclid(b).checked_neg()
+}
+
+fn extended_gcd(a: i64, b: i64) -> Option<(i64, i64, i64)> {
+    if b == 0 {
+        Some((a.checked_abs()?, a.signum(), 0))
+    } else {
+        let (d, p, q) = extended_gcd(b, a.checked_rem_euclid(b)?)?;
+        Some((d, q, p.checked_sub(a.checked_div_euclid(b)?.checked_mul(q)?)?))
+    }
+}
+
+impl Poly {
+    // None when a coefficient overflows an i64
+    pub fn checked_add(&self, other: &Poly) -> Option<Poly> {
+        let mut terms = self.terms.clone();
+        for (monomial, coefficient) in &other.terms {
+            let sum = terms.entry(monomial.clone()).or_insert(0);
+            *sum = sum.checked_add(*coefficient)?;
+        }
+        terms.retain(|_, c| *c != 0);
+        Some(Poly { terms })
+    }
+
+    pub fn checked_mul(&self, other: &Poly) -> Option<Poly> {
+        let mut terms = BTreeMap::new();
+        for (m1, c1) in &self.terms {
+            for (m2, c2) in &other.terms {
+                let mut monomial = [m1.as_slice(), m2.as_slice()].concat();
+                monomial.sort();
+                let sum = terms.entry(monomial).or_insert(0i64);
+                *sum = sum.checked_add(c1.checked_mul(*c2)?)?;
+            }
+        }
+        terms.retain(|_, c| *c != 0);
+        Some(Poly { terms })
+    }
+}
+
+impl fmt::Display for Poly {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.terms.is_empty() {
+            return write!(f, "0");
+        }
+        // highest degree first, so the constant ends up last
+        let mut terms: Vec<_> = self.terms.iter().collect();
+        terms.sort_by_key(|(monomial, _)| std::cmp::Reverse(monomial.len()));
+        for (i, (monomial, coefficient)) in terms.into_iter().enumerate() {
+            let sign = if *coefficient < 0 { "-" } else { "+" };
+            if i > 0 {
+                write!(f, " {} ", sign)?;
+            } else if *coefficient < 0 {
+                write!(f, "-")?;
+            }
+            let magnitude = coefficient.unsigned_abs();
+            let names: Vec<String> = monomial.iter().map(|a| format!("m{}", a)).collect();
+            if names.is_empty() {
+                write!(f, "{}", magnitude)?;
+            } else if magnitude == 1 {
+                write!(f, "{}", names.join("*"))?;
+            } else {
+                write!(f, "{}*{}", magnitude, names.join("*"))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+// Runs a day 2 program with some cells replaced by unknowns. Cells whose
+// value can't be expressed (e.g. read through an unknown address) become
+// `None`; that's only an error if something later depends on them.
+pub struct Symbolic {
+    memory: Vec<Option<Poly>>,
+}
+
+impl Symbolic {
+    pub fn new(program: &[i32], unknowns: &[usize]) -> Result<Symbolic, String> {
+        let mut memory: Vec<Option<Poly>> = program
+            .iter()
+            .map(|v| Some(Poly::constant(*v as i64)))
+            .collect();
+        for address in unknowns {
+            let cell = memory
+                .get_mut(*address)
+                .ok_or_else(|| format!("unknown {} is outside memory", address))?;
+            *cell = Some(Poly::unknown(*address));
+        }
+        Ok(Symbolic { memory })
+    }
+
+    pub fn run(&mut self) -> Result<(), String> {
+        let mut i = 0;
+        while i < self.memory.len() {
+            let opcode = self.concrete(i)?;
+            match opcode {
+                1 | 2 => {
+                    let dest = self.address(i + 3)?;
+                    let s1 = self.read(i + 1)?;
+                    let s2 = self.read(i + 2)?;
+                    self.memory[dest] = match (s1, s2) {
+                        (Some(a), Some(b)) => {
+                            let result = if opcode == 1 { a.checked_add(&b) } else { a.checked_mul(&b) };
+                            Some(result.ok_or_else(|| format!("instruction at {} overflows an i64", i))?)
+                        }
+                        _ => None,
+                    };
+                    i += 4;
+                }
+                99 => break,
+                _ => i += 1,
+            }
+        }
+        Ok(())
+    }
+
+    pub fn cell(&self, address: usize) -> Result<&Poly, String> {
+        self.memory
+            .get(address)
+            .ok_or_else(|| format!("cell {} is outside memory", address))?
+            .as_ref()
+            .ok_or_else(|| format!("cell {} depends on an unknown address", address))
+    }
+
+    fn concrete(&self, address: usize) -> Result<i64, String> {
+        let value = self.cell(address)?;
+        value
+            .as_constant()
+            .ok_or_else(|| format!("cell {} is {}, not a constant", address, value))
+    }
+
+    fn address(&self, address: usize) -> Result<usize, String> {
+        let value = self.concrete(address)?;
+        if value < 0 || value as usize >= self.memory.len() {
+            return Err(format!("cell {} points outside memory: {}", address, value));
+        }
+        Ok(value as usize)
+    }
+
+    // The value the operand at `address` points to, or None when the pointer
+    // itself is unknown.
+    fn read(&self, address: usize) -> Result<Option<Poly>, String> {
+        if self.cell(address)?.as_constant().is_none() {
+            return Ok(None);
+        }
+        Ok(self.memory[self.address(address)?].clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // memory[0] = memory[9] * memory[12] + memory[10], with memory[12] = 5
+    const PROGRAM: [i32; 13] = [2, 9, 12, 0, 1, 0, 10, 0, 99, 0, 0, 0, 5];
+
+    #[test]
+    fn test_linear_expression() {
+        let mut machine = Symbolic::new(&PROGRAM, &[9, 10]).unwrap();
+        machine.run().unwrap();
+        let expr = machine.cell(0).unwrap();
+        assert_eq!(expr.to_string(), "5*m9 + m10");
+        assert_eq!(expr.solve(23, &[(9, 0..5), (10, 0..5)]), Ok(vec![vec![4, 3]]));
+        assert_eq!(expr.solve(23, &[(9, 0..10), (10, 0..10)]), Ok(vec![vec![3, 8], vec![4, 3]]));
+        assert_eq!(expr.solve(100, &[(9, 0..5), (10, 0..5)]), Ok(vec![]));
+    }
+
+    #[test]
+    fn test_nonlinear_expression() {
+        let mut machine = Symbolic::new(&PROGRAM, &[9, 10, 12]).unwrap();
+        machine.run().unwrap();
+        let expr = machine.cell(0).unwrap();
+        assert_eq!(expr.to_string(), "m9*m12 + m10");
+        assert!(expr.solve(23, &[(9, 0..5), (10, 0..5), (12, 0..5)]).is_err());
+    }
+
+    #[test]
+    fn test_unknown_address() {
+        // memory[0] = memory[m1] + memory[6], which can't be known
+        let program = [1, 5, 6, 0, 1, 0, 0, 0, 99];
+        let mut machine = Symbolic::new(&program, &[1]).unwrap();
+        machine.run().unwrap();
+        assert!(machine.cell(0).is_err());
+
+        // and jumping into it is an error
+        let program = [1, 5, 5, 4, 0, 99];
+        let mut machine = Symbolic::new(&program, &[1]).unwrap();
+        assert!(machine.run().is_err());
+
+        // ... but it's fine if the unknowable value is overwritten first
+        let program = [1, 1, 2, 3, 1, 1, 2, 3, 99];
+        let mut machine = Symbolic::new(&program, &[1, 2]).unwrap();
+        machine.run().unwrap();
+        assert_eq!(machine.cell(3).unwrap().to_string(), "m1 + m2");
+    }
+
+    #[test]
+    fn test_outside_memory() {
+        assert!(Symbolic::new(&PROGRAM, &[13]).is_err());
+        let mut machine = Symbolic::new(&PROGRAM, &[9]).unwrap();
+        machine.run().unwrap();
+        assert_eq!(machine.cell(13), Err(String::from("cell 13 is outside memory")));
+
+        // running off the end partway through an instruction
+        let mut machine = Symbolic::new(&[1, 0, 0], &[]).unwrap();
+        assert!(machine.run().is_err());
+    }
+
+    #[test]
+    fn test_overflow() {
+        // squares 2^20 in place: 2^40, then 2^80, past what an i64 holds
+        let program = [2, 13, 13, 13, 2, 13, 13, 13, 2, 13, 13, 13, 99, 1 << 20];
+        let mut machine = Symbolic::new(&program, &[]).unwrap();
+        assert_eq!(machine.run(), Err(String::from("instruction at 4 overflows an i64")));
+
+        let expr = Poly::unknown(1).checked_add(&Poly::constant(i64::MIN)).unwrap();
+        assert!(expr.solve(1, &[(1, 0..10)]).is_err());
+        assert!(Poly::constant(i64::MAX).checked_add(&Poly::constant(1)).is_none());
+        assert!(Poly::constant(i64::MAX).checked_mul(&Poly::constant(2)).is_none());
+    }
+}
@@ -17,9 +17,17 @@ fn main() {
 
     let layer = combine_layers(&layers);
     print_layer(&layer);
+    let pixels: Vec<Vec<bool>> = layer
+        .chunks(25)
+        .map(|row| row.iter().map(|x| *x == '1').collect())
+        .collect();
+    match ocr::recognize(&pixels) {
+        Ok(text) => println!("{}", text),
+        Err(e) => println!("can't read the image: {}", e),
+    }
 }
 
-fn combine_layers(layers: &Vec<Vec<char>>) -> Vec<char> {
+fn combine_layers(layers: &[Vec<char>]) -> Vec<char> {
     let mut combined_layer = vec!['2'; 150];
     for layer in layers {
         for i in 0..150 {
@@ -31,7 +39,7 @@ fn combine_layers(layers: &Vec<Vec<char>>) -> Vec<char> {
     combined_layer
 }
 
-fn print_layer(layer: &Vec<char>) {
+fn print_layer(layer: &[char]) {
     for i in 0..6 {
         for j in 0..25 {
             if layer[i * 25 + j] == '0' {
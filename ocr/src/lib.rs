@@ -0,0 +1,156 @@
+// Reads the block capitals some puzzles draw their answers in: six pixels
+// high, four wide (three for I, five for Y), with a blank column between
+// letters. Y fills a whole five column cell, so it can run straight into the
+// letter after it.
+
+const HEIGHT: usize = 6;
+
+const GLYPHS: [(char, [&str; HEIGHT]); 18] = [
+    ('A', [".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', ["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', [".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', ["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', ["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', [".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', ["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('I', ["###", ".#.", ".#.", ".#.", ".#.", "###"]),
+    ('J', ["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', ["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', ["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', [".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', ["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', ["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', [".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', ["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Y', ["#...#", "#...#", ".#.#.", "..#..", "..#..", "..#.."]),
+    ('Z', ["####", "...#", "..#.", ".#..", "#...", "####"]),
+];
+
+// One letter's pixels as rows of `#` and `.`.
+fn picture(rows: &[Vec<bool>], columns: std::ops::Range<usize>) -> Vec<String> {
+    rows.iter()
+        .map(|row| {
+            columns
+                .clone()
+                .map(|x| if row.get(x) == Some(&true) { '#' } else { '.' })
+                .collect()
+        })
+        .collect()
+}
+
+// Turns lit pixels (`true`), top row first, into letters. Blank rows and
+// columns around the text are ignored. Letters that touch are read off the
+// front of the run of lit columns, the widest match first. Any glyph that
+// isn't a known letter is an error that shows what it looked like.
+pub fn recognize(pixels: &[Vec<bool>]) -> Result<String, String> {
+    let lit = |row: &Vec<bool>| row.iter().any(|pixel| *pixel);
+    let top = pixels.iter().position(lit).ok_or("no text to read")?;
+    let bottom = pixels.iter().rposition(lit).unwrap() + 1;
+    let rows = &pixels[top..bottom];
+    if rows.len() != HEIGHT {
+        return Err(format!("text is {} pixels high, letters are {}", rows.len(), HEIGHT));
+    }
+
+    let width = rows.iter().map(|row| row.len()).max().unwrap_or(0);
+    let column_lit = |x: usize| rows.iter().any(|row| row.get(x) == Some(&true));
+    let mut text = String::new();
+    let mut x = 0;
+    while x < width {
+        if !column_lit(x) {
+            x += 1;
+            continue;
+        }
+        let mut start = x;
+        while x < width && column_lit(x) {
+            x += 1;
+        }
+        while start < x {
+            let found = GLYPHS
+                .iter()
+                .filter(|(_, glyph)| start + glyph[0].len() <= x)
+                .filter(|(_, glyph)| glyph.iter().eq(picture(rows, start..start + glyph[0].len()).iter()))
+                .max_by_key(|(_, glyph)| glyph[0].len());
+            match found {
+                Some((letter, glyph)) => {
+                    text.push(*letter);
+                    start += glyph[0].len();
+                }
+                None => {
+                    return Err(format!(
+                        "unknown glyph at column {}:\n{}",
+                        start,
+                        picture(rows, start..x).join("\n")
+                    ))
+                }
+            }
+        }
+    }
+    Ok(text)
+}
+
+// The same for text drawn with `on` for lit pixels and anything else for dark
+// ones, one line per row.
+pub fn recognize_text(text: &str, on: char) -> Result<String, String> {
+    let pixels: Vec<Vec<bool>> = text
+        .lines()
+        .map(|line| line.chars().map(|c| c == on).collect())
+        .collect();
+    recognize(&pixels)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_every_letter() {
+        let mut rows = vec![String::new(); HEIGHT];
+        for (_, glyph) in GLYPHS.iter() {
+            for (row, line) in rows.iter_mut().zip(glyph.iter()) {
+                row.push_str(line);
+                row.push('.');
+            }
+        }
+        let expected: String = GLYPHS.iter().map(|(letter, _)| *letter).collect();
+        assert_eq!(recognize_text(&rows.join("\n"), '#'), Ok(expected));
+    }
+
+    #[test]
+    fn test_touching_letters() {
+        // five column cells, where Y leaves no gap before the K
+        let text = "\
+.##..#...##..#.####
+#..#.#...##.#..#...
+#..#..#.#.##...###.
+####...#..#.#..#...
+#..#...#..#.#..#...
+#..#...#..#..#.####";
+        assert_eq!(recognize_text(text, '#'), Ok("AYKE".to_string()));
+    }
+
+    #[test]
+    fn test_padding() {
+        let text = "
+            ......
+            ..##..
+            .#..#.
+            .#..#.
+            .####.
+            .#..#.
+            .#..#.
+            ......";
+        let text: Vec<&str> = text.lines().map(|line| line.trim()).collect();
+        assert_eq!(recognize_text(&text.join("\n"), '#'), Ok("A".to_string()));
+    }
+
+    #[test]
+    fn test_errors() {
+        assert!(recognize_text("....\n....", '#').is_err());
+        assert!(recognize_text("#\n#\n#", '#').unwrap_err().contains("3 pixels high"));
+        let square = "####\n#..#\n#..#\n#..#\n#..#\n####";
+        assert_eq!(
+            recognize_text(square, '#'),
+            Err(format!("unknown glyph at column 0:\n{}", square))
+        );
+    }
+}